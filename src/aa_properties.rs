@@ -0,0 +1,696 @@
+//! Physicochemical properties of amino acids.
+//!
+//! Per-residue properties are stored in tables indexed by [`AA::rank`].
+//! The redundant residues `B`, `J` and `Z` take the mean of the two residues
+//! that they represent, and `X` takes the mean of the 20 standard residues.
+//! Where no measurement exists for selenocysteine (`U`) or pyrrolysine
+//! (`O`), they take the values of cysteine and lysine respectively.
+//!
+//! The sequence-level calculators follow the definitions used by ExPASy
+//! ProtParam.
+//!
+//! # Examples:
+//!
+//! ```
+//! use seqrs::aa_properties::{gravy, molecular_weight, MassType};
+//! use seqrs::alphabet::AA;
+//!
+//! let seq = vec![AA::G, AA::G];
+//! let mw = molecular_weight(&seq, MassType::Average);
+//! assert!((mw - 132.118).abs() < 0.01);
+//!
+//! assert!((gravy(&[AA::I, AA::V]) - 4.35).abs() < 1e-9);
+//! ```
+//!
+//! [`AA::rank`]: ../alphabet/trait.Alphabet.html#tymethod.rank
+
+use std::borrow::Borrow;
+use std::convert::TryFrom;
+
+use crate::alphabet::{Alphabet, AA, AA20};
+
+/// Which isotopic masses to use for mass calculations.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum MassType {
+    /// The mass of the most abundant isotope of each element.
+    Monoisotopic,
+    /// The average mass of each element, weighted by natural abundance.
+    Average,
+}
+
+impl MassType {
+    /// The mass of a water molecule, added to residue masses to give the
+    /// mass of a free peptide.
+    fn water(&self) -> f64 {
+        match self {
+            MassType::Monoisotopic => 18.01056,
+            MassType::Average => 18.01528,
+        }
+    }
+}
+
+/// The mass of a residue in daltons, i.e. the mass of the free amino acid
+/// minus a water molecule.
+#[inline]
+pub fn residue_mass(aa: &AA, mass: MassType) -> f64 {
+    match mass {
+        MassType::Monoisotopic => MONOISOTOPIC_MASS[aa.rank()],
+        MassType::Average => AVERAGE_MASS[aa.rank()],
+    }
+}
+
+/// The Kyte-Doolittle hydropathy of a residue.
+#[inline]
+pub fn hydropathy(aa: &AA) -> f64 {
+    KYTE_DOOLITTLE[aa.rank()]
+}
+
+/// The volume of a residue in cubic angstroms.
+#[inline]
+pub fn volume(aa: &AA) -> f64 {
+    VOLUME[aa.rank()]
+}
+
+/// The pKa of a residue's side chain, or `None` if it isn't ionisable.
+///
+/// `B` and `Z` take the pKa of their ionisable member (`D` and `E`).
+#[inline]
+pub fn pka(aa: &AA) -> Option<f64> {
+    PKA[aa.rank()]
+}
+
+/// The charge of a residue's side chain when fully ionised.
+///
+/// Acidic side chains have a charge of -1 and basic side chains +1.
+/// `B` and `Z` have a charge of -0.5, because only one of the residues that
+/// they represent is ionisable. `X` is treated as uncharged.
+#[inline]
+pub fn charge(aa: &AA) -> f64 {
+    CHARGE[aa.rank()]
+}
+
+/// The molecular weight of a peptide in daltons.
+pub fn molecular_weight<I>(seq: I, mass: MassType) -> f64
+where
+    I: IntoIterator,
+    I::Item: Borrow<AA>,
+{
+    let mut n = 0;
+    let total: f64 = seq
+        .into_iter()
+        .inspect(|_| n += 1)
+        .map(|aa| residue_mass(aa.borrow(), mass))
+        .sum();
+
+    if n == 0 {
+        0.0
+    } else {
+        total + mass.water()
+    }
+}
+
+/// The fraction of a group with a given pKa that is charged at a pH.
+#[inline]
+fn charged_fraction(pka: f64, charge: f64, ph: f64) -> f64 {
+    if charge > 0.0 {
+        charge / (1.0 + 10_f64.powf(ph - pka))
+    } else {
+        charge / (1.0 + 10_f64.powf(pka - ph))
+    }
+}
+
+/// The net charge of a peptide at a given pH, including the termini.
+pub fn charge_at_ph<I>(seq: I, ph: f64) -> f64
+where
+    I: IntoIterator,
+    I::Item: Borrow<AA>,
+{
+    let mut n = 0;
+    let mut total = 0.0;
+
+    for aa in seq {
+        let aa = aa.borrow();
+        n += 1;
+        if let Some(p) = pka(aa) {
+            total += charged_fraction(p, charge(aa), ph);
+        }
+    }
+
+    if n > 0 {
+        total += charged_fraction(PKA_N_TERMINUS, 1.0, ph);
+        total += charged_fraction(PKA_C_TERMINUS, -1.0, ph);
+    }
+
+    total
+}
+
+/// The isoelectric point of a peptide, i.e. the pH at which it has no net
+/// charge.
+///
+/// This is found by bisection to within 0.001 pH units.
+pub fn isoelectric_point<I>(seq: I) -> f64
+where
+    I: IntoIterator,
+    I::Item: Borrow<AA>,
+{
+    let seq: Vec<AA> = seq.into_iter().map(|aa| *aa.borrow()).collect();
+
+    let mut low = 0.0;
+    let mut high = 14.0;
+    while high - low > 0.001 {
+        let mid = (low + high) / 2.0;
+        // Net charge decreases as pH increases.
+        if charge_at_ph(&seq, mid) > 0.0 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    (low + high) / 2.0
+}
+
+/// The molar extinction coefficient of a protein at 280nm, in
+/// M<sup>-1</sup> cm<sup>-1</sup> (Pace et al., 1995).
+///
+/// Returns a tuple of the coefficient assuming all cysteines are reduced,
+/// and assuming that all pairs of cysteines form cystines.
+pub fn extinction_coefficient<I>(seq: I) -> (usize, usize)
+where
+    I: IntoIterator,
+    I::Item: Borrow<AA>,
+{
+    let mut n_w = 0;
+    let mut n_y = 0;
+    let mut n_c = 0;
+
+    for aa in seq {
+        match aa.borrow() {
+            AA::W => n_w += 1,
+            AA::Y => n_y += 1,
+            AA::C => n_c += 1,
+            _ => (),
+        }
+    }
+
+    let reduced = n_w * 5500 + n_y * 1490;
+    (reduced, reduced + (n_c / 2) * 125)
+}
+
+/// The grand average of hydropathy (GRAVY), i.e. the mean Kyte-Doolittle
+/// hydropathy of the residues.
+pub fn gravy<I>(seq: I) -> f64
+where
+    I: IntoIterator,
+    I::Item: Borrow<AA>,
+{
+    let mut n = 0;
+    let total: f64 = seq
+        .into_iter()
+        .inspect(|_| n += 1)
+        .map(|aa| hydropathy(aa.borrow()))
+        .sum();
+
+    if n == 0 {
+        0.0
+    } else {
+        total / n as f64
+    }
+}
+
+/// The AA20 ranks of the standard residues that a residue represents.
+fn standard_ranks(aa: &AA) -> Vec<usize> {
+    match aa {
+        AA::U => vec![AA20::C.rank()],
+        AA::O => vec![AA20::K.rank()],
+        a => match AA20::try_from(a) {
+            Ok(b) => vec![b.rank()],
+            Err(_) => a
+                .redundant_matches()
+                .iter()
+                .filter_map(|b| AA20::try_from(b).ok())
+                .map(|b| b.rank())
+                .collect(),
+        },
+    }
+}
+
+/// The instability index of a protein (Guruprasad et al., 1990).
+///
+/// Proteins with an index above 40 are predicted to be unstable.
+/// Dipeptides containing redundant residues take the mean weight of the
+/// dipeptides that they represent.
+pub fn instability_index<I>(seq: I) -> f64
+where
+    I: IntoIterator,
+    I::Item: Borrow<AA>,
+{
+    let ranks: Vec<Vec<usize>> = seq
+        .into_iter()
+        .map(|aa| standard_ranks(aa.borrow()))
+        .collect();
+
+    if ranks.is_empty() {
+        return 0.0;
+    }
+
+    let total: f64 = ranks
+        .windows(2)
+        .map(|w| {
+            let sum: f64 = w[0]
+                .iter()
+                .flat_map(|&a| w[1].iter().map(move |&b| DIWV[a][b]))
+                .sum();
+            sum / (w[0].len() * w[1].len()) as f64
+        })
+        .sum();
+
+    10.0 * total / ranks.len() as f64
+}
+
+/// The mean Kyte-Doolittle hydropathy of each window along a sequence.
+///
+/// Returns one value for each complete window, so a sequence shorter than
+/// the window yields no values.
+///
+/// # Panics
+///
+/// Panics if the window size is 0.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::aa_properties::hydropathy_profile;
+/// use seqrs::alphabet::AA;
+///
+/// let profile = hydropathy_profile(&[AA::I, AA::K, AA::I, AA::K], 2);
+/// assert_eq!(profile.len(), 3);
+/// assert!(profile.iter().all(|&h| (h - 0.3).abs() < 1e-9));
+/// ```
+pub fn hydropathy_profile<I>(seq: I, window: usize) -> Vec<f64>
+where
+    I: IntoIterator,
+    I::Item: Borrow<AA>,
+{
+    assert!(window > 0, "Window size must be greater than 0.");
+
+    let values: Vec<f64> = seq.into_iter().map(|aa| hydropathy(aa.borrow())).collect();
+    values
+        .windows(window)
+        .map(|w| w.iter().sum::<f64>() / window as f64)
+        .collect()
+}
+
+// TABLES!
+
+// pKa values of the peptide termini.
+const PKA_N_TERMINUS: f64 = 9.0;
+const PKA_C_TERMINUS: f64 = 2.0;
+
+// Side chain pKa values.
+const PKA: [Option<f64>; 26] = [
+    None, // A
+    Some(4.05), // B
+    Some(9.0), // C
+    Some(4.05), // D
+    Some(4.45), // E
+    None, // F
+    None, // G
+    Some(5.98), // H
+    None, // I
+    None, // J
+    Some(10.0), // K
+    None, // L
+    None, // M
+    None, // N
+    None, // O
+    None, // P
+    None, // Q
+    Some(12.0), // R
+    None, // S
+    None, // T
+    Some(5.2), // U
+    None, // V
+    None, // W
+    None, // X
+    Some(10.0), // Y
+    Some(4.45), // Z
+];
+
+// Side chain charges when ionised.
+const CHARGE: [f64; 26] = [
+    0.0, // A
+    -0.5, // B
+    -1.0, // C
+    -1.0, // D
+    -1.0, // E
+    0.0, // F
+    0.0, // G
+    1.0, // H
+    0.0, // I
+    0.0, // J
+    1.0, // K
+    0.0, // L
+    0.0, // M
+    0.0, // N
+    0.0, // O
+    0.0, // P
+    0.0, // Q
+    1.0, // R
+    0.0, // S
+    0.0, // T
+    -1.0, // U
+    0.0, // V
+    0.0, // W
+    0.0, // X
+    -1.0, // Y
+    -0.5, // Z
+];
+
+// Monoisotopic residue masses in daltons.
+const MONOISOTOPIC_MASS: [f64; 26] = [
+    71.03711, // A
+    114.53493, // B
+    103.00919, // C
+    115.02694, // D
+    129.04259, // E
+    147.06841, // F
+    57.02146, // G
+    137.05891, // H
+    113.08406, // I
+    113.08406, // J
+    128.09496, // K
+    113.08406, // L
+    131.04049, // M
+    114.04293, // N
+    237.14773, // O
+    97.05276, // P
+    128.05858, // Q
+    156.10111, // R
+    87.03203, // S
+    101.04768, // T
+    150.95364, // U
+    99.06841, // V
+    186.07931, // W
+    118.80572, // X
+    163.06333, // Y
+    128.55059, // Z
+];
+
+// Average residue masses in daltons.
+const AVERAGE_MASS: [f64; 26] = [
+    71.0788, // A
+    114.5962, // B
+    103.1388, // C
+    115.0886, // D
+    129.1155, // E
+    147.1766, // F
+    57.0519, // G
+    137.1411, // H
+    113.1594, // I
+    113.1594, // J
+    128.1741, // K
+    113.1594, // L
+    131.1926, // M
+    114.1038, // N
+    237.2982, // O
+    97.1167, // P
+    128.1307, // Q
+    156.1875, // R
+    87.0782, // S
+    101.1051, // T
+    150.0388, // U
+    99.1326, // V
+    186.2132, // W
+    118.886, // X
+    163.176, // Y
+    128.6231, // Z
+];
+
+// Kyte-Doolittle hydropathy.
+const KYTE_DOOLITTLE: [f64; 26] = [
+    1.8, // A
+    -3.5, // B
+    2.5, // C
+    -3.5, // D
+    -3.5, // E
+    2.8, // F
+    -0.4, // G
+    -3.2, // H
+    4.5, // I
+    4.15, // J
+    -3.9, // K
+    3.8, // L
+    1.9, // M
+    -3.5, // N
+    -3.9, // O
+    -1.6, // P
+    -3.5, // Q
+    -4.5, // R
+    -0.8, // S
+    -0.7, // T
+    2.5, // U
+    4.2, // V
+    -0.9, // W
+    -0.49, // X
+    -1.3, // Y
+    -3.5, // Z
+];
+
+// Residue volumes in cubic angstroms (Zamyatnin, 1972).
+const VOLUME: [f64; 26] = [
+    88.6, // A
+    112.6, // B
+    108.5, // C
+    111.1, // D
+    138.4, // E
+    189.9, // F
+    60.1, // G
+    153.2, // H
+    166.7, // I
+    166.7, // J
+    168.6, // K
+    166.7, // L
+    162.9, // M
+    114.1, // N
+    168.6, // O
+    112.7, // P
+    143.8, // Q
+    173.4, // R
+    89.0, // S
+    116.1, // T
+    108.5, // U
+    140.0, // V
+    227.8, // W
+    141.26, // X
+    193.6, // Y
+    141.1, // Z
+];
+
+// Dipeptide instability weights (Guruprasad et al., 1990), indexed by the
+// AA20 ranks of the first and second residues.
+const DIWV: [[f64; 20]; 20] = [
+    // A
+    [
+        1.0, 44.94, -7.49, 1.0, 1.0, 1.0, -7.49, 1.0, 1.0, 1.0, 1.0, 1.0, 20.26, 1.0, 1.0, 1.0, 1.0,
+        1.0, 1.0, 1.0,
+    ],
+    // C
+    [
+        1.0, 1.0, 20.26, 1.0, 1.0, 1.0, 33.6, 1.0, 1.0, 20.26, 33.6, 1.0, 20.26, -6.54, 1.0, 1.0,
+        33.6, -6.54, 24.68, 1.0,
+    ],
+    // D
+    [
+        1.0, 1.0, 1.0, 1.0, -6.54, 1.0, 1.0, 1.0, -7.49, 1.0, 1.0, 1.0, 1.0, 1.0, -6.54, 20.26,
+        -14.03, 1.0, 1.0, 1.0,
+    ],
+    // E
+    [
+        1.0, 44.94, 20.26, 33.6, 1.0, 1.0, -6.54, 20.26, 1.0, 1.0, 1.0, 1.0, 20.26, 20.26, 1.0,
+        20.26, 1.0, 1.0, -14.03, 1.0,
+    ],
+    // F
+    [
+        1.0, 1.0, 13.34, 1.0, 1.0, 1.0, 1.0, 1.0, -14.03, 1.0, 1.0, 1.0, 20.26, 1.0, 1.0, 1.0, 1.0,
+        1.0, 1.0, 33.601,
+    ],
+    // G
+    [
+        -7.49, 1.0, 1.0, -6.54, 1.0, 13.34, 1.0, -7.49, -7.49, 1.0, 1.0, -7.49, 1.0, 1.0, 1.0, 1.0,
+        -7.49, 1.0, 13.34, -7.49,
+    ],
+    // H
+    [
+        1.0, 1.0, 1.0, 1.0, -9.37, -9.37, 1.0, 44.94, 24.68, 1.0, 1.0, 24.68, -1.88, 1.0, 1.0, 1.0,
+        -6.54, 1.0, -1.88, 44.94,
+    ],
+    // I
+    [
+        1.0, 1.0, 1.0, 44.94, 1.0, 1.0, 13.34, 1.0, -7.49, 20.26, 1.0, 1.0, -1.88, 1.0, 1.0, 1.0,
+        1.0, -7.49, 1.0, 1.0,
+    ],
+    // K
+    [
+        1.0, 1.0, 1.0, 1.0, 1.0, -7.49, 1.0, -7.49, 1.0, -7.49, 33.6, 1.0, -6.54, 24.64, 33.6, 1.0,
+        1.0, -7.49, 1.0, 1.0,
+    ],
+    // L
+    [
+        1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, -7.49, 1.0, 1.0, 1.0, 20.26, 33.6, 20.26, 1.0, 1.0,
+        1.0, 24.68, 1.0,
+    ],
+    // M
+    [
+        13.34, 1.0, 1.0, 1.0, 1.0, 1.0, 58.28, 1.0, 1.0, 1.0, -1.88, 1.0, 44.94, -6.54, -6.54,
+        44.94, -1.88, 1.0, 1.0, 24.68,
+    ],
+    // N
+    [
+        1.0, -1.88, 1.0, 1.0, -14.03, -14.03, 1.0, 44.94, 24.68, 1.0, 1.0, 1.0, -1.88, -6.54, 1.0,
+        1.0, -7.49, 1.0, -9.37, 1.0,
+    ],
+    // P
+    [
+        20.26, -6.54, -6.54, 18.38, 20.26, 1.0, 1.0, 1.0, 1.0, 1.0, -6.54, 1.0, 20.26, 20.26, -6.54,
+        20.26, 1.0, 20.26, -1.88, 1.0,
+    ],
+    // Q
+    [
+        1.0, -6.54, 20.26, 20.26, -6.54, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 20.26, 20.26, 1.0,
+        44.94, 1.0, -6.54, 1.0, -6.54,
+    ],
+    // R
+    [
+        1.0, 1.0, 1.0, 1.0, 1.0, -7.49, 20.26, 1.0, 1.0, 1.0, 1.0, 13.34, 20.26, 20.26, 58.28,
+        44.94, 1.0, 1.0, 58.28, -6.54,
+    ],
+    // S
+    [
+        1.0, 33.6, 1.0, 20.26, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 44.94, 20.26, 20.26, 20.26,
+        1.0, 1.0, 1.0, 1.0,
+    ],
+    // T
+    [
+        1.0, 1.0, 1.0, 20.26, 13.34, -7.49, 1.0, 1.0, 1.0, 1.0, 1.0, -14.03, 1.0, -6.54, 1.0, 1.0,
+        1.0, 1.0, -14.03, 1.0,
+    ],
+    // V
+    [
+        1.0, 1.0, -14.03, 1.0, 1.0, -7.49, 1.0, 1.0, -1.88, 1.0, 1.0, 1.0, 20.26, 1.0, 1.0, 1.0,
+        -7.49, 1.0, 1.0, -6.54,
+    ],
+    // W
+    [
+        -14.03, 1.0, 1.0, 1.0, 1.0, -9.37, 24.68, 1.0, 1.0, 13.34, 24.68, 13.34, 1.0, 1.0, 1.0, 1.0,
+        -14.03, -7.49, 1.0, 1.0,
+    ],
+    // Y
+    [
+        24.68, 1.0, 24.68, -6.54, 1.0, -7.49, 13.34, 1.0, 1.0, 1.0, 44.94, 1.0, 13.34, 1.0, -15.91,
+        1.0, -7.49, 1.0, -9.37, 13.34,
+    ],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn test_redundant_are_averaged() {
+        for aa in &[AA::B, AA::J, AA::Z] {
+            let members = aa.redundant_matches();
+            let mean = |f: &dyn Fn(&AA) -> f64| {
+                members.iter().map(f).sum::<f64>() / members.len() as f64
+            };
+
+            assert!(close(hydropathy(aa), mean(&hydropathy)));
+            assert!(close(volume(aa), mean(&volume)));
+            assert!(close(
+                residue_mass(aa, MassType::Average),
+                mean(&|b| residue_mass(b, MassType::Average))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_molecular_weight() {
+        let gg = vec![AA::G, AA::G];
+        assert!((molecular_weight(&gg, MassType::Monoisotopic) - 132.0535).abs() < 1e-3);
+        assert!((molecular_weight(&gg, MassType::Average) - 132.1190).abs() < 1e-3);
+        assert_eq!(molecular_weight(Vec::<AA>::new(), MassType::Average), 0.0);
+    }
+
+    #[test]
+    fn test_isoelectric_point() {
+        let basic = vec![AA::K; 10];
+        let acidic = vec![AA::D; 10];
+        assert!(isoelectric_point(&basic) > 9.0);
+        assert!(isoelectric_point(&acidic) < 4.0);
+
+        let seq = vec![AA::M, AA::K, AA::D, AA::E, AA::H];
+        assert!(charge_at_ph(&seq, isoelectric_point(&seq)).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_extinction_coefficient() {
+        let seq = vec![AA::W, AA::Y, AA::C, AA::A, AA::C, AA::C];
+        assert_eq!(extinction_coefficient(&seq), (6990, 7115));
+    }
+
+    #[test]
+    fn test_instability_index() {
+        assert_eq!(instability_index(Vec::<AA>::new()), 0.0);
+        assert!(close(instability_index(&[AA::A, AA::A]), 5.0));
+        assert!(close(instability_index(&[AA::A, AA::C]), 10.0 * 44.94 / 2.0));
+        // J is I or L, which have different weights following C.
+        assert!(close(
+            instability_index(&[AA::C, AA::J]),
+            10.0 * (1.0 + 20.26) / 2.0 / 2.0
+        ));
+        assert!(close(
+            instability_index(&[AA::R, AA::B]),
+            10.0 * (1.0 + 13.34) / 2.0 / 2.0
+        ));
+    }
+
+    #[test]
+    fn test_hydropathy_profile() {
+        let seq = vec![AA::A, AA::R, AA::N];
+        assert!(hydropathy_profile(&seq, 4).is_empty());
+        assert_eq!(hydropathy_profile(&seq, 1), vec![1.8, -4.5, -3.5]);
+    }
+
+    proptest! {
+        // The mean of a profile with window 1 is the GRAVY.
+        #[test]
+        fn test_profile_matches_gravy(seq in vec(select(AA::variants()), 1..50)) {
+            let profile = hydropathy_profile(&seq, 1);
+            let mean = profile.iter().sum::<f64>() / profile.len() as f64;
+            assert!(close(mean, gravy(&seq)));
+
+            let whole = hydropathy_profile(&seq, seq.len());
+            assert_eq!(whole.len(), 1);
+            assert!(close(whole[0], gravy(&seq)));
+        }
+
+        #[test]
+        fn test_calculators_dont_crash(seq in vec(select(AA::variants()), 0..50)) {
+            let pi = isoelectric_point(&seq);
+            assert!((0.0..=14.0).contains(&pi));
+            let _dummy = instability_index(&seq);
+
+            // Isotopic masses differ by less than a dalton per residue.
+            let mono = molecular_weight(&seq, MassType::Monoisotopic);
+            let average = molecular_weight(&seq, MassType::Average);
+            assert!((mono - average).abs() <= seq.len() as f64);
+        }
+    }
+}
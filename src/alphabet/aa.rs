@@ -1,10 +1,11 @@
 /// Definitions for the Protein alphabet
+use std::borrow::Borrow;
 use std::convert::TryFrom;
 
 use crate::alphabet::Alphabet;
 use crate::alphabet::RedundantAlphabet;
 use crate::matcher::Match;
-use crate::errors::{SeqError, SeqErrorKind};
+use crate::errors::{Position, SeqError, SeqErrorKind};
 
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -69,40 +70,149 @@ impl AA {
         }
     }
 
-    /*
-    pub fn name(&self) -> String {
+    /// The full name of the residue.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::alphabet::AA;
+    ///
+    /// assert_eq!(AA::W.name(), "Tryptophan");
+    /// assert_eq!(AA::B.name(), "Aspartic acid or Asparagine");
+    /// ```
+    pub fn name(&self) -> &'static str {
         match &self {
-            AA::A => String::from("Alanine"),
-            AA::B => String::from("Aspartic acid or Asparagine"),
-            AA::C => String::from("Cysteine"),
-            AA::D => String::from("Aspartic acid"),
-            AA::E => String::from("Glutamic acid"),
-            AA::F => String::from("Phenylalanine"),
-            AA::G => String::from("Glycine"),
-            AA::H => String::from("Histidine"),
-            AA::I => String::from("Isoleucine"),
-            AA::J => String::from("Isoleucine or Leucine"),
-            AA::K => String::from("Lysine"),
-            AA::L => String::from("Leucine"),
-            AA::M => String::from("Methionine"),
-            AA::N => String::from("Asparagine"),
-            AA::O => String::from("Pyroleucine"),
-            AA::P => String::from("Proline"),
-            AA::Q => String::from("Glutamine"),
-            AA::R => String::from("Arginine"),
-            AA::S => String::from("Serine"),
-            AA::T => String::from("Threonine"),
-            AA::U => String::from("Selenocysteine"),
-            AA::V => String::from("Valine"),
-            AA::W => String::from("Tryptophan"),
-            AA::X => String::from("Any amino acid"),
-            AA::Y => String::from("Tyrosine"),
-            AA::Z => String::from("Glutamine or Glutamic acid"),
+            AA::A => "Alanine",
+            AA::B => "Aspartic acid or Asparagine",
+            AA::C => "Cysteine",
+            AA::D => "Aspartic acid",
+            AA::E => "Glutamic acid",
+            AA::F => "Phenylalanine",
+            AA::G => "Glycine",
+            AA::H => "Histidine",
+            AA::I => "Isoleucine",
+            AA::J => "Isoleucine or Leucine",
+            AA::K => "Lysine",
+            AA::L => "Leucine",
+            AA::M => "Methionine",
+            AA::N => "Asparagine",
+            AA::O => "Pyrrolysine",
+            AA::P => "Proline",
+            AA::Q => "Glutamine",
+            AA::R => "Arginine",
+            AA::S => "Serine",
+            AA::T => "Threonine",
+            AA::U => "Selenocysteine",
+            AA::V => "Valine",
+            AA::W => "Tryptophan",
+            AA::X => "Any amino acid",
+            AA::Y => "Tyrosine",
+            AA::Z => "Glutamine or Glutamic acid",
         }
     }
-    */
 
-    fn redundant_matches(&self) -> Vec<Self> {
+    /// The IUPAC three letter code of the residue.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::alphabet::AA;
+    ///
+    /// assert_eq!(AA::U.three_letter_code(), "Sec");
+    /// assert_eq!(AA::X.three_letter_code(), "Xaa");
+    /// ```
+    pub fn three_letter_code(&self) -> &'static str {
+        match &self {
+            AA::A => "Ala",
+            AA::B => "Asx",
+            AA::C => "Cys",
+            AA::D => "Asp",
+            AA::E => "Glu",
+            AA::F => "Phe",
+            AA::G => "Gly",
+            AA::H => "His",
+            AA::I => "Ile",
+            AA::J => "Xle",
+            AA::K => "Lys",
+            AA::L => "Leu",
+            AA::M => "Met",
+            AA::N => "Asn",
+            AA::O => "Pyl",
+            AA::P => "Pro",
+            AA::Q => "Gln",
+            AA::R => "Arg",
+            AA::S => "Ser",
+            AA::T => "Thr",
+            AA::U => "Sec",
+            AA::V => "Val",
+            AA::W => "Trp",
+            AA::X => "Xaa",
+            AA::Y => "Tyr",
+            AA::Z => "Glx",
+        }
+    }
+
+    /// Parse a three letter code, ignoring case (e.g. `Ala`, `ALA`).
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::alphabet::AA;
+    ///
+    /// assert_eq!(AA::from_three_letter_code("Pyl").unwrap(), AA::O);
+    /// assert_eq!(AA::from_three_letter_code("GLY").unwrap(), AA::G);
+    /// assert!(AA::from_three_letter_code("Foo").is_err());
+    /// ```
+    pub fn from_three_letter_code(code: &str) -> Result<Self, SeqError> {
+        let bytes = code.as_bytes();
+        if bytes.len() != 3 {
+            return Err(SeqErrorKind::ThreeLetterCodeReadError {
+                code: code.to_string(),
+            }
+            .into());
+        }
+
+        let normalised = [
+            bytes[0].to_ascii_uppercase(),
+            bytes[1].to_ascii_lowercase(),
+            bytes[2].to_ascii_lowercase(),
+        ];
+
+        match &normalised {
+            b"Ala" => Ok(AA::A),
+            b"Asx" => Ok(AA::B),
+            b"Cys" => Ok(AA::C),
+            b"Asp" => Ok(AA::D),
+            b"Glu" => Ok(AA::E),
+            b"Phe" => Ok(AA::F),
+            b"Gly" => Ok(AA::G),
+            b"His" => Ok(AA::H),
+            b"Ile" => Ok(AA::I),
+            b"Xle" => Ok(AA::J),
+            b"Lys" => Ok(AA::K),
+            b"Leu" => Ok(AA::L),
+            b"Met" => Ok(AA::M),
+            b"Asn" => Ok(AA::N),
+            b"Pyl" => Ok(AA::O),
+            b"Pro" => Ok(AA::P),
+            b"Gln" => Ok(AA::Q),
+            b"Arg" => Ok(AA::R),
+            b"Ser" => Ok(AA::S),
+            b"Thr" => Ok(AA::T),
+            b"Sec" => Ok(AA::U),
+            b"Val" => Ok(AA::V),
+            b"Trp" => Ok(AA::W),
+            b"Xaa" => Ok(AA::X),
+            b"Tyr" => Ok(AA::Y),
+            b"Glx" => Ok(AA::Z),
+            _ => Err(SeqErrorKind::ThreeLetterCodeReadError {
+                code: code.to_string(),
+            }
+            .into()),
+        }
+    }
+
+    pub(crate) fn redundant_matches(&self) -> Vec<Self> {
         use super::AA::*;
         match self {
             B => vec![D, N],
@@ -116,6 +226,51 @@ impl AA {
     }
 }
 
+/// Parse a protein sequence written with three letter codes.
+///
+/// Codes may be concatenated (e.g. `AlaGlySer`, as in HGVS notation) or
+/// separated by whitespace (e.g. `ALA GLY SER`, as in PDB files).
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::{parse_three_letter, AA};
+///
+/// let seq = parse_three_letter("MetAlaSec").unwrap();
+/// assert_eq!(seq, vec![AA::M, AA::A, AA::U]);
+///
+/// let seq = parse_three_letter("MET ALA\nSEC").unwrap();
+/// assert_eq!(seq, vec![AA::M, AA::A, AA::U]);
+///
+/// assert!(parse_three_letter("MetAl").is_err());
+/// ```
+pub fn parse_three_letter(seq: &str) -> Result<Vec<AA>, SeqError> {
+    let mut output = Vec::with_capacity(seq.len() / 3);
+
+    for word in seq.split_whitespace() {
+        let mut rest = word;
+        while !rest.is_empty() {
+            let split = rest.char_indices().nth(3).map_or(rest.len(), |(i, _)| i);
+            let (code, tail) = rest.split_at(split);
+            output.push(AA::from_three_letter_code(code)?);
+            rest = tail;
+        }
+    }
+
+    Ok(output)
+}
+
+/// Format a protein sequence with three letter codes (e.g. `MetAlaSec`).
+pub fn to_three_letter<I>(seq: I) -> String
+where
+    I: IntoIterator,
+    I::Item: Borrow<AA>,
+{
+    seq.into_iter()
+        .map(|aa| aa.borrow().three_letter_code())
+        .collect()
+}
+
 impl Alphabet for AA {
     /// The number of letters in this alphabet.
     fn cardinality() -> usize {
@@ -195,7 +350,11 @@ try_from_borrowed! {
                 b'X' => Ok(AA::X),
                 b'Y' => Ok(AA::Y),
                 b'Z' => Ok(AA::Z),
-                b => Err(SeqErrorKind::AlphabetReadError { base: b as char }.into()),
+                b => Err(SeqErrorKind::AlphabetReadError {
+                    base: b as char,
+                    position: Position(None),
+                }
+                .into()),
             }
         }
     }
@@ -349,6 +508,7 @@ impl RedundantAlphabet for AA {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::collection::vec;
     use proptest::prelude::any;
     use proptest::sample::select;
     use proptest::{proptest, proptest_helper};
@@ -368,7 +528,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_three_letter_codes() {
+        assert_eq!(AA::from_three_letter_code("Ala").unwrap(), AA::A);
+        assert_eq!(AA::from_three_letter_code("sec").unwrap(), AA::U);
+        assert_eq!(AA::from_three_letter_code("XAA").unwrap(), AA::X);
+        assert_eq!(
+            AA::from_three_letter_code("Al").unwrap_err().kind(),
+            &SeqErrorKind::ThreeLetterCodeReadError {
+                code: "Al".to_string()
+            }
+        );
+        assert!(AA::from_three_letter_code("Aé").is_err());
+
+        let seq = parse_three_letter("  Met Lys\tTrpPyl ").unwrap();
+        assert_eq!(seq, vec![AA::M, AA::K, AA::W, AA::O]);
+        assert_eq!(to_three_letter(&seq), "MetLysTrpPyl");
+        assert!(parse_three_letter("").unwrap().is_empty());
+        assert!(parse_three_letter("MetFoo").is_err());
+    }
+
     proptest! {
+        // Three letter codes should round-trip.
+        #[test]
+        fn test_three_letter_recovers_original(seq in vec(select(AA::variants()), 0..20)) {
+            assert_eq!(parse_three_letter(&to_three_letter(&seq)).unwrap(), seq);
+            for b in &seq {
+                assert_eq!(AA::from_three_letter_code(b.three_letter_code()).unwrap(), *b);
+                assert!(!b.name().is_empty());
+            }
+        }
+
         // Basic parsing properties.
         #[test]
         fn test_from_u8_doesnt_crash(c in any::<u8>()) {
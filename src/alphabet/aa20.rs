@@ -0,0 +1,283 @@
+//! The non-redundant 20 letter standard amino acid alphabet.
+use std::convert::TryFrom;
+
+use crate::alphabet::Alphabet;
+use crate::alphabet::{AA, AA22};
+use crate::errors::{Position, SeqError, SeqErrorKind};
+use crate::matcher::Match;
+
+/// The 20 standard amino acids, with dense ranks from 0 to 19.
+///
+/// Residues are ordered alphabetically by their one letter codes.
+/// Redundant (`B`, `J`, `X`, `Z`) and nonstandard (`O`, `U`) residues can't
+/// be represented, see [`AA22`] for an alphabet including the nonstandard
+/// residues.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::{Alphabet, AA, AA20};
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(AA20::try_from(AA::K).unwrap().rank(), 8);
+/// assert!(AA20::try_from(AA::X).is_err());
+/// assert_eq!(AA::from(AA20::W), AA::W);
+/// ```
+///
+/// [`AA22`]: enum.AA22.html
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+pub enum AA20 {
+    A,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    K,
+    L,
+    M,
+    N,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    V,
+    W,
+    Y,
+}
+
+impl Alphabet for AA20 {
+    /// The number of letters in this alphabet.
+    fn cardinality() -> usize {
+        20
+    }
+
+    fn rank(&self) -> usize {
+        *self as usize
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(r < Self::cardinality());
+        std::mem::transmute::<u8, Self>(r as u8)
+    }
+
+    /// Returns a Vec of all of the Enum variants.
+    fn variants() -> Vec<Self> {
+        use self::AA20::*;
+        vec![
+            A, C, D, E, F, G, H, I, K, L, M, N, P, Q, R, S, T, V, W, Y,
+        ]
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&u8> for AA20 {
+        type Error = SeqError;
+        fn try_from(base: &u8) -> Result<Self, Self::Error> {
+            match base.to_ascii_uppercase() {
+                b'A' => Ok(AA20::A),
+                b'C' => Ok(AA20::C),
+                b'D' => Ok(AA20::D),
+                b'E' => Ok(AA20::E),
+                b'F' => Ok(AA20::F),
+                b'G' => Ok(AA20::G),
+                b'H' => Ok(AA20::H),
+                b'I' => Ok(AA20::I),
+                b'K' => Ok(AA20::K),
+                b'L' => Ok(AA20::L),
+                b'M' => Ok(AA20::M),
+                b'N' => Ok(AA20::N),
+                b'P' => Ok(AA20::P),
+                b'Q' => Ok(AA20::Q),
+                b'R' => Ok(AA20::R),
+                b'S' => Ok(AA20::S),
+                b'T' => Ok(AA20::T),
+                b'V' => Ok(AA20::V),
+                b'W' => Ok(AA20::W),
+                b'Y' => Ok(AA20::Y),
+                b => Err(SeqErrorKind::AlphabetReadError {
+                    base: b as char,
+                    position: Position(None),
+                }
+                .into()),
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&char> for AA20 {
+        type Error = SeqError;
+        fn try_from(base: &char) -> Result<Self, Self::Error> {
+            crate::utils::char_to_byte(base).and_then(|b| Self::try_from(&b))
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&AA20> for u8 {
+        fn from(base: &AA20) -> Self {
+            u8::from(AA::from(base))
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&AA20> for char {
+        fn from(base: &AA20) -> Self {
+            u8::from(base) as char
+        }
+    }
+}
+
+impl std::fmt::Display for AA20 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+impl Match<AA20> for AA20 {
+    fn matches(&self, other: &AA20) -> bool {
+        self == other
+    }
+}
+
+from_borrowed! {
+    impl From<&AA20> for AA {
+        fn from(base: &AA20) -> Self {
+            match base {
+                AA20::A => AA::A,
+                AA20::C => AA::C,
+                AA20::D => AA::D,
+                AA20::E => AA::E,
+                AA20::F => AA::F,
+                AA20::G => AA::G,
+                AA20::H => AA::H,
+                AA20::I => AA::I,
+                AA20::K => AA::K,
+                AA20::L => AA::L,
+                AA20::M => AA::M,
+                AA20::N => AA::N,
+                AA20::P => AA::P,
+                AA20::Q => AA::Q,
+                AA20::R => AA::R,
+                AA20::S => AA::S,
+                AA20::T => AA::T,
+                AA20::V => AA::V,
+                AA20::W => AA::W,
+                AA20::Y => AA::Y,
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&AA> for AA20 {
+        type Error = SeqError;
+        fn try_from(base: &AA) -> Result<Self, Self::Error> {
+            match base {
+                AA::A => Ok(AA20::A),
+                AA::C => Ok(AA20::C),
+                AA::D => Ok(AA20::D),
+                AA::E => Ok(AA20::E),
+                AA::F => Ok(AA20::F),
+                AA::G => Ok(AA20::G),
+                AA::H => Ok(AA20::H),
+                AA::I => Ok(AA20::I),
+                AA::K => Ok(AA20::K),
+                AA::L => Ok(AA20::L),
+                AA::M => Ok(AA20::M),
+                AA::N => Ok(AA20::N),
+                AA::P => Ok(AA20::P),
+                AA::Q => Ok(AA20::Q),
+                AA::R => Ok(AA20::R),
+                AA::S => Ok(AA20::S),
+                AA::T => Ok(AA20::T),
+                AA::V => Ok(AA20::V),
+                AA::W => Ok(AA20::W),
+                AA::Y => Ok(AA20::Y),
+                b => Err(SeqErrorKind::RedundantAlphabetConversionError {
+                    base: char::from(b),
+                }
+                .into()),
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&AA22> for AA20 {
+        type Error = SeqError;
+        fn try_from(base: &AA22) -> Result<Self, Self::Error> {
+            // The standard residues share ranks between the two alphabets.
+            AA20::from_rank(base.rank()).ok_or_else(|| {
+                SeqErrorKind::RedundantAlphabetConversionError {
+                    base: char::from(base),
+                }
+                .into()
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::RedundantAlphabet;
+    use proptest::prelude::any;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_cardinality() {
+        assert_eq!(AA20::cardinality(), AA20::variants().len());
+    }
+
+    #[test]
+    fn test_from_aa() {
+        for b in &[AA::B, AA::J, AA::O, AA::U, AA::X, AA::Z] {
+            let err = AA20::try_from(b).unwrap_err();
+            assert_eq!(
+                err.kind(),
+                &SeqErrorKind::RedundantAlphabetConversionError {
+                    base: char::from(b)
+                }
+            );
+        }
+        assert_eq!(AA20::try_from(AA::Y).unwrap(), AA20::Y);
+    }
+
+    proptest! {
+        #[test]
+        fn test_from_u8_doesnt_crash(c in any::<u8>()) {
+            let _dummy = AA20::try_from(c);
+        }
+
+        #[test]
+        fn test_from_to_u8_recovers_original(b in select(AA20::variants())) {
+            assert_eq!(AA20::try_from(u8::from(b)).unwrap(), b);
+            assert_eq!(
+                AA20::try_from(u8::from(b).to_ascii_lowercase()).unwrap(),
+                b
+            );
+        }
+
+        #[test]
+        fn test_aa_roundtrip(b in select(AA20::variants())) {
+            assert_eq!(AA20::try_from(AA::from(b)).unwrap(), b);
+            assert_eq!(AA20::try_from(AA22::from(b)).unwrap(), b);
+        }
+
+        // Parsing as AA20 should only succeed for non-redundant standard residues.
+        #[test]
+        fn test_agrees_with_aa(b in select(AA::variants())) {
+            let standard = b.is_iupac() && !b.is_redundant();
+            assert_eq!(AA20::try_from(b).is_ok(), standard);
+            assert_eq!(AA20::try_from(u8::from(b)).is_ok(), standard);
+        }
+    }
+}
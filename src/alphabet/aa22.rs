@@ -0,0 +1,197 @@
+//! The non-redundant 22 letter amino acid alphabet.
+use std::convert::TryFrom;
+
+use crate::alphabet::Alphabet;
+use crate::alphabet::{AA, AA20};
+use crate::errors::SeqError;
+use crate::matcher::Match;
+
+/// The 20 standard amino acids plus pyrrolysine (`O`) and
+/// selenocysteine (`U`).
+///
+/// The standard residues have the same ranks as in [`AA20`], and the
+/// nonstandard residues follow them with ranks 20 and 21.
+///
+/// [`AA20`]: enum.AA20.html
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+pub enum AA22 {
+    A,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    K,
+    L,
+    M,
+    N,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    V,
+    W,
+    Y,
+    O,
+    U,
+}
+
+impl Alphabet for AA22 {
+    /// The number of letters in this alphabet.
+    fn cardinality() -> usize {
+        22
+    }
+
+    fn rank(&self) -> usize {
+        *self as usize
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(r < Self::cardinality());
+        std::mem::transmute::<u8, Self>(r as u8)
+    }
+
+    /// Returns a Vec of all of the Enum variants.
+    fn variants() -> Vec<Self> {
+        use self::AA22::*;
+        vec![
+            A, C, D, E, F, G, H, I, K, L, M, N, P, Q, R, S, T, V, W, Y, O, U,
+        ]
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&u8> for AA22 {
+        type Error = SeqError;
+        fn try_from(base: &u8) -> Result<Self, Self::Error> {
+            match base.to_ascii_uppercase() {
+                b'O' => Ok(AA22::O),
+                b'U' => Ok(AA22::U),
+                b => AA20::try_from(b).map(AA22::from),
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&char> for AA22 {
+        type Error = SeqError;
+        fn try_from(base: &char) -> Result<Self, Self::Error> {
+            crate::utils::char_to_byte(base).and_then(|b| Self::try_from(&b))
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&AA22> for u8 {
+        fn from(base: &AA22) -> Self {
+            u8::from(AA::from(base))
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&AA22> for char {
+        fn from(base: &AA22) -> Self {
+            u8::from(base) as char
+        }
+    }
+}
+
+impl std::fmt::Display for AA22 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+impl Match<AA22> for AA22 {
+    fn matches(&self, other: &AA22) -> bool {
+        self == other
+    }
+}
+
+from_borrowed! {
+    impl From<&AA20> for AA22 {
+        fn from(base: &AA20) -> Self {
+            unsafe { AA22::from_rank_unsafe(base.rank()) }
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&AA22> for AA {
+        fn from(base: &AA22) -> Self {
+            match base {
+                AA22::O => AA::O,
+                AA22::U => AA::U,
+                b => AA::from(AA20::try_from(b).expect("Standard AA22 is AA20.")),
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&AA> for AA22 {
+        type Error = SeqError;
+        fn try_from(base: &AA) -> Result<Self, Self::Error> {
+            match base {
+                AA::O => Ok(AA22::O),
+                AA::U => Ok(AA22::U),
+                b => AA20::try_from(b).map(AA22::from),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::RedundantAlphabet;
+    use proptest::prelude::any;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_cardinality() {
+        assert_eq!(AA22::cardinality(), AA22::variants().len());
+    }
+
+    #[test]
+    fn test_from_aa() {
+        assert_eq!(AA22::try_from(AA::U).unwrap(), AA22::U);
+        assert_eq!(AA22::try_from(AA::D).unwrap(), AA22::D);
+        assert!(AA22::try_from(AA::B).is_err());
+        assert!(AA20::try_from(AA22::O).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_from_u8_doesnt_crash(c in any::<u8>()) {
+            let _dummy = AA22::try_from(c);
+        }
+
+        #[test]
+        fn test_from_to_u8_recovers_original(b in select(AA22::variants())) {
+            assert_eq!(AA22::try_from(u8::from(b)).unwrap(), b);
+            assert_eq!(
+                AA22::try_from(u8::from(b).to_ascii_lowercase()).unwrap(),
+                b
+            );
+        }
+
+        #[test]
+        fn test_aa_roundtrip(b in select(AA22::variants())) {
+            assert_eq!(AA22::try_from(AA::from(b)).unwrap(), b);
+        }
+
+        // Only the redundant residues can't be represented.
+        #[test]
+        fn test_agrees_with_aa(b in select(AA::variants())) {
+            assert_eq!(AA22::try_from(b).is_ok(), !b.is_redundant());
+        }
+    }
+}
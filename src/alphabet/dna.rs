@@ -6,7 +6,7 @@ use crate::alphabet::RedundantAlphabet;
 use crate::matcher::Match;
 use crate::alphabet::DNA4;
 use crate::complement::Complement;
-use crate::errors::{SeqError, SeqErrorKind};
+use crate::errors::{Position, SeqError, SeqErrorKind};
 
 /// A fully redundant DNA alphabet represented as an enum.
 #[repr(u8)]
@@ -32,7 +32,7 @@ pub enum DNA {
 impl DNA {
     /// For redundant matches, returns a vec of each non-redundant
     /// base that it contains.
-    fn redundant_matches(&self) -> Vec<Self> {
+    pub(crate) fn redundant_matches(&self) -> Vec<Self> {
         use super::DNA::*;
         match self {
             M => vec![A, C],
@@ -108,7 +108,11 @@ try_from_borrowed! {
                 b'D' => Ok(DNA::D),
                 b'B' => Ok(DNA::B),
                 b'N' => Ok(DNA::N),
-                b => Err(SeqErrorKind::AlphabetReadError { base: b as char }.into()),
+                b => Err(SeqErrorKind::AlphabetReadError {
+                    base: b as char,
+                    position: Position(None),
+                }
+                .into()),
             }
         }
     }
@@ -4,7 +4,7 @@ use std::convert::TryFrom;
 use crate::alphabet::Alphabet;
 use crate::alphabet::DNA;
 use crate::complement::Complement;
-use crate::errors::{SeqError, SeqErrorKind};
+use crate::errors::{Position, SeqError, SeqErrorKind};
 use crate::matcher::Match;
 
 /// A Non-redundant four letter DNA alphabet.
@@ -47,7 +47,11 @@ try_from_borrowed! {
                 b'C' => Ok(DNA4::C),
                 b'G' => Ok(DNA4::G),
                 b'T' => Ok(DNA4::T),
-                b => Err(SeqErrorKind::AlphabetReadError { base: b as char }.into()),
+                b => Err(SeqErrorKind::AlphabetReadError {
+                    base: b as char,
+                    position: Position(None),
+                }
+                .into()),
             }
         }
     }
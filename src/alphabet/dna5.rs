@@ -0,0 +1,317 @@
+//! A five letter DNA alphabet with a single redundant base, `N`.
+use std::convert::TryFrom;
+
+use crate::alphabet::Alphabet;
+use crate::alphabet::RedundantAlphabet;
+use crate::alphabet::{DNA, DNA4};
+use crate::complement::Complement;
+use crate::errors::{Position, SeqError, SeqErrorKind};
+use crate::matcher::Match;
+
+/// A five letter DNA alphabet, with `N` representing any base.
+///
+/// This covers the letters used by most sequencing platforms, and keeps
+/// codon tables small (125 entries) compared to the fully redundant
+/// [`DNA`] alphabet.
+///
+/// [`DNA`]: enum.DNA.html
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum DNA5 {
+    A = 0,
+    C = 1,
+    G = 2,
+    T = 3,
+    N = 4,
+}
+
+impl Alphabet for DNA5 {
+    /// The number of letters in this alphabet.
+    fn cardinality() -> usize {
+        5
+    }
+
+    fn rank(&self) -> usize {
+        *self as usize
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(r < Self::cardinality());
+        std::mem::transmute::<u8, Self>(r as u8)
+    }
+
+    /// Returns a Vec of all of the Enum variants.
+    fn variants() -> Vec<Self> {
+        vec![DNA5::A, DNA5::C, DNA5::G, DNA5::T, DNA5::N]
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&u8> for DNA5 {
+        type Error = SeqError;
+        fn try_from(base: &u8) -> Result<Self, Self::Error> {
+            match base.to_ascii_uppercase() {
+                b'A' => Ok(DNA5::A),
+                b'C' => Ok(DNA5::C),
+                b'G' => Ok(DNA5::G),
+                b'T' => Ok(DNA5::T),
+                b'N' => Ok(DNA5::N),
+                b => Err(SeqErrorKind::AlphabetReadError {
+                    base: b as char,
+                    position: Position(None),
+                }
+                .into()),
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&char> for DNA5 {
+        type Error = SeqError;
+        fn try_from(base: &char) -> Result<Self, Self::Error> {
+            crate::utils::char_to_byte(base).and_then(|b| Self::try_from(&b))
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&DNA5> for u8 {
+        fn from(base: &DNA5) -> Self {
+            match base {
+                DNA5::A => b'A',
+                DNA5::C => b'C',
+                DNA5::G => b'G',
+                DNA5::T => b'T',
+                DNA5::N => b'N',
+            }
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&DNA5> for char {
+        fn from(base: &DNA5) -> Self {
+            u8::from(base) as char
+        }
+    }
+}
+
+impl std::fmt::Display for DNA5 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+impl Complement for DNA5 {
+    type Compl = DNA5;
+    fn complement(&self) -> Self::Compl {
+        match self {
+            DNA5::N => DNA5::N,
+            b => {
+                let comp = (*b as u8) ^ 0b11;
+                unsafe { std::mem::transmute::<u8, Self::Compl>(comp) }
+            }
+        }
+    }
+}
+
+impl Match<DNA5> for DNA5 {
+    fn matches(&self, other: &DNA5) -> bool {
+        match (self, other) {
+            (DNA5::N, _) | (_, DNA5::N) => true,
+            (a, b) => a == b,
+        }
+    }
+}
+
+impl Match<DNA> for DNA5 {
+    fn matches(&self, other: &DNA) -> bool {
+        DNA::from(self).matches(other)
+    }
+}
+
+impl Match<DNA5> for DNA {
+    fn matches(&self, other: &DNA5) -> bool {
+        self.matches(&DNA::from(other))
+    }
+}
+
+/// Combinations of bases without their own letter are promoted to `N`.
+impl RedundantAlphabet for DNA5 {
+    fn union(&self, other: &Self) -> Self {
+        if self == other {
+            *self
+        } else {
+            DNA5::N
+        }
+    }
+
+    fn intersection(&self, other: &Self) -> Option<Self> {
+        match (self, other) {
+            (DNA5::N, b) => Some(*b),
+            (a, DNA5::N) => Some(*a),
+            (a, b) if a == b => Some(*a),
+            _ => None,
+        }
+    }
+
+    fn difference(&self, other: &Self) -> Option<Self> {
+        match (self, other) {
+            (_, DNA5::N) => None,
+            (a, b) if a == b => None,
+            (a, _) => Some(*a),
+        }
+    }
+
+    fn is_redundant(&self) -> bool {
+        self == &DNA5::N
+    }
+}
+
+from_borrowed! {
+    impl From<&DNA4> for DNA5 {
+        fn from(base: &DNA4) -> Self {
+            // DNA4 and DNA5 share ranks for the non-redundant bases.
+            unsafe { std::mem::transmute::<u8, DNA5>(*base as u8) }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&DNA5> for DNA4 {
+        type Error = SeqError;
+        fn try_from(base: &DNA5) -> Result<Self, Self::Error> {
+            match base {
+                DNA5::N => Err(SeqErrorKind::RedundantAlphabetConversionError {
+                    base: char::from(base),
+                }
+                .into()),
+                b => unsafe { Ok(std::mem::transmute::<u8, DNA4>(*b as u8)) },
+            }
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&DNA5> for DNA {
+        fn from(base: &DNA5) -> Self {
+            match base {
+                DNA5::A => DNA::A,
+                DNA5::C => DNA::C,
+                DNA5::G => DNA::G,
+                DNA5::T => DNA::T,
+                DNA5::N => DNA::N,
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&DNA> for DNA5 {
+        type Error = SeqError;
+        fn try_from(base: &DNA) -> Result<Self, Self::Error> {
+            match base {
+                DNA::A => Ok(DNA5::A),
+                DNA::C => Ok(DNA5::C),
+                DNA::G => Ok(DNA5::G),
+                DNA::T => Ok(DNA5::T),
+                DNA::N => Ok(DNA5::N),
+                b => Err(SeqErrorKind::RedundantAlphabetConversionError {
+                    base: char::from(b),
+                }
+                .into()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complement::IntoReverseComplement;
+    use proptest::prelude::any;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_complement_vec() {
+        use super::DNA5::*;
+        let d = vec![A, T, N, G, C];
+        let c: Vec<DNA5> = d.iter().reverse_complement().collect();
+        assert_eq!(c, vec![G, C, N, A, T]);
+    }
+
+    #[test]
+    fn test_match() {
+        assert!(DNA5::A.matches(&DNA5::A));
+        assert!(DNA5::A.matches(&DNA5::N));
+        assert!(DNA5::N.matches(&DNA5::T));
+        assert!(DNA5::A.doesnt_match(&DNA5::T));
+
+        assert!(DNA5::A.matches(&DNA::R));
+        assert!(DNA5::N.matches(&DNA::Y));
+        assert!(DNA5::A.doesnt_match(&DNA::Y));
+        assert!(DNA::S.matches(&DNA5::G));
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(DNA5::try_from(DNA::N).unwrap(), DNA5::N);
+        assert!(DNA5::try_from(DNA::R).is_err());
+        assert_eq!(DNA4::try_from(DNA5::G).unwrap(), DNA4::G);
+        assert!(DNA4::try_from(DNA5::N).is_err());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        assert_eq!(DNA5::A.union(&DNA5::A), DNA5::A);
+        assert_eq!(DNA5::A.union(&DNA5::C), DNA5::N);
+        assert_eq!(DNA5::N.intersection(&DNA5::C), Some(DNA5::C));
+        assert_eq!(DNA5::A.intersection(&DNA5::C), None);
+        assert_eq!(DNA5::A.difference(&DNA5::N), None);
+        assert_eq!(DNA5::N.difference(&DNA5::A), Some(DNA5::N));
+    }
+
+    proptest! {
+        #[test]
+        fn test_from_u8_doesnt_crash(c in any::<u8>()) {
+            let _dummy = DNA5::try_from(c);
+        }
+
+        #[test]
+        fn test_from_to_u8_recovers_original(b in select(DNA5::variants())) {
+            assert_eq!(DNA5::try_from(u8::from(b)).unwrap(), b);
+            assert_eq!(
+                DNA5::try_from(u8::from(b).to_ascii_lowercase()).unwrap(),
+                b
+            );
+        }
+
+        #[test]
+        fn test_from_to_char_recovers_original(b in select(DNA5::variants())) {
+            assert_eq!(DNA5::try_from(char::from(b)).unwrap(), b);
+        }
+
+        // DNA5 should behave like the matching subset of DNA.
+        #[test]
+        fn test_consistent_with_dna(
+            base1 in select(DNA5::variants()),
+            base2 in select(DNA5::variants()),
+        ) {
+            let dna1 = DNA::from(base1);
+            assert_eq!(DNA5::try_from(dna1).unwrap(), base1);
+            assert_eq!(DNA::from(base1.complement()), dna1.complement());
+            assert_eq!(base1.matches(&base2), dna1.matches(&DNA::from(base2)));
+        }
+
+        #[test]
+        fn test_union_is_reciprocal(
+            base1 in select(DNA5::variants()),
+            base2 in select(DNA5::variants()),
+        ) {
+            assert_eq!(base1.union(&base2), base2.union(&base1));
+            assert_eq!(base1.intersection(&base2), base2.intersection(&base1));
+        }
+    } // End proptest!
+}
@@ -0,0 +1,338 @@
+//! Dot-bracket notation for nucleic acid secondary structures.
+use std::borrow::Borrow;
+use std::convert::TryFrom;
+
+use crate::alphabet::{Alphabet, RedundantAlphabet, RNA};
+use crate::complement::Complement;
+use crate::errors::{Position, SeqError, SeqErrorKind};
+use crate::matcher::Match;
+
+/// A position in a dot-bracket structure.
+///
+/// Round brackets describe the nested base pairs, and square, curly, and
+/// angle brackets describe pseudoknots that cross them.
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+pub enum DotBracket {
+    Unpaired,
+    RoundOpen,
+    RoundClose,
+    SquareOpen,
+    SquareClose,
+    CurlyOpen,
+    CurlyClose,
+    AngleOpen,
+    AngleClose,
+}
+
+impl DotBracket {
+    /// The bracket type, from 0 (round) to 3 (angle), or `None` if unpaired.
+    pub fn level(&self) -> Option<usize> {
+        match self {
+            DotBracket::Unpaired => None,
+            b => Some((*b as usize - 1) / 2),
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        *self != DotBracket::Unpaired && (*self as u8) % 2 == 1
+    }
+
+    pub fn is_close(&self) -> bool {
+        *self != DotBracket::Unpaired && (*self as u8) % 2 == 0
+    }
+
+    pub fn is_paired(&self) -> bool {
+        *self != DotBracket::Unpaired
+    }
+}
+
+impl Alphabet for DotBracket {
+    /// The number of letters in this alphabet.
+    fn cardinality() -> usize {
+        9
+    }
+
+    fn rank(&self) -> usize {
+        *self as usize
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(r < Self::cardinality());
+        std::mem::transmute::<u8, Self>(r as u8)
+    }
+
+    /// Returns a Vec of all of the Enum variants.
+    fn variants() -> Vec<Self> {
+        use self::DotBracket::*;
+        vec![
+            Unpaired,
+            RoundOpen,
+            RoundClose,
+            SquareOpen,
+            SquareClose,
+            CurlyOpen,
+            CurlyClose,
+            AngleOpen,
+            AngleClose,
+        ]
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&u8> for DotBracket {
+        type Error = SeqError;
+        fn try_from(base: &u8) -> Result<Self, Self::Error> {
+            match base {
+                b'.' => Ok(DotBracket::Unpaired),
+                b'(' => Ok(DotBracket::RoundOpen),
+                b')' => Ok(DotBracket::RoundClose),
+                b'[' => Ok(DotBracket::SquareOpen),
+                b']' => Ok(DotBracket::SquareClose),
+                b'{' => Ok(DotBracket::CurlyOpen),
+                b'}' => Ok(DotBracket::CurlyClose),
+                b'<' => Ok(DotBracket::AngleOpen),
+                b'>' => Ok(DotBracket::AngleClose),
+                b => Err(SeqErrorKind::AlphabetReadError {
+                    base: *b as char,
+                    position: Position(None),
+                }
+                .into()),
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&char> for DotBracket {
+        type Error = SeqError;
+        fn try_from(base: &char) -> Result<Self, Self::Error> {
+            crate::utils::char_to_byte(base).and_then(|b| Self::try_from(&b))
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&DotBracket> for u8 {
+        fn from(base: &DotBracket) -> Self {
+            match base {
+                DotBracket::Unpaired => b'.',
+                DotBracket::RoundOpen => b'(',
+                DotBracket::RoundClose => b')',
+                DotBracket::SquareOpen => b'[',
+                DotBracket::SquareClose => b']',
+                DotBracket::CurlyOpen => b'{',
+                DotBracket::CurlyClose => b'}',
+                DotBracket::AngleOpen => b'<',
+                DotBracket::AngleClose => b'>',
+            }
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&DotBracket> for char {
+        fn from(base: &DotBracket) -> Self {
+            u8::from(base) as char
+        }
+    }
+}
+
+impl std::fmt::Display for DotBracket {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+impl Match<DotBracket> for DotBracket {
+    fn matches(&self, other: &DotBracket) -> bool {
+        self == other
+    }
+}
+
+/// Find the base pairs in a structure, as `(open, close)` positions sorted
+/// by the opening position.
+///
+/// Fails with an `UnbalancedBracketError` at the first closing bracket
+/// without a partner, or the first opening bracket left unclosed.
+pub fn dot_bracket_pairs<T, I>(structure: I) -> Result<Vec<(usize, usize)>, SeqError>
+where
+    T: Borrow<DotBracket>,
+    I: IntoIterator<Item = T>,
+{
+    let mut stacks: [Vec<usize>; 4] = Default::default();
+    let mut pairs = Vec::new();
+
+    for (i, b) in structure.into_iter().enumerate() {
+        let b = b.borrow();
+        let level = match b.level() {
+            Some(l) => l,
+            None => continue,
+        };
+
+        if b.is_open() {
+            stacks[level].push(i);
+        } else {
+            let open = stacks[level].pop().ok_or_else(|| {
+                SeqError::from(SeqErrorKind::UnbalancedBracketError { position: i })
+            })?;
+            pairs.push((open, i));
+        }
+    }
+
+    if let Some(&position) = stacks.iter().filter_map(|s| s.first()).min() {
+        return Err(SeqErrorKind::UnbalancedBracketError { position: position }.into());
+    }
+
+    pairs.sort();
+    Ok(pairs)
+}
+
+/// Parse a dot-bracket string, returning its base pairs.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::parse_dot_bracket;
+///
+/// // A hairpin with a pseudoknot.
+/// let pairs = parse_dot_bracket("((..[[..))..]]").unwrap();
+/// assert_eq!(pairs, vec![(0, 9), (1, 8), (4, 13), (5, 12)]);
+///
+/// assert!(parse_dot_bracket("((..)").is_err());
+/// assert!(parse_dot_bracket("(..]").is_err());
+/// ```
+pub fn parse_dot_bracket(structure: &str) -> Result<Vec<(usize, usize)>, SeqError> {
+    let structure: Result<Vec<DotBracket>, SeqError> =
+        structure.bytes().map(DotBracket::try_from).collect();
+    dot_bracket_pairs(structure?)
+}
+
+/// Is this a Watson-Crick (A-U, G-C) or G-U wobble pair?
+///
+/// Redundant bases are never considered to pair.
+pub fn is_canonical_pair(a: &RNA, b: &RNA) -> bool {
+    if a.is_redundant() || b.is_redundant() {
+        return false;
+    }
+
+    match (a, b) {
+        (RNA::G, RNA::U) | (RNA::U, RNA::G) => true,
+        (a, b) => &a.complement() == b,
+    }
+}
+
+/// Check that every base pair in a structure is a canonical pair, returning
+/// the pairs.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::{check_pairs, DotBracket, RNA};
+/// use std::convert::TryFrom;
+///
+/// let seq: Vec<RNA> = b"GGAAAUC".iter().map(|b| RNA::try_from(b).unwrap()).collect();
+/// let ss: Vec<DotBracket> = b"((...))".iter().map(|b| DotBracket::try_from(b).unwrap()).collect();
+///
+/// assert_eq!(check_pairs(&seq, &ss).unwrap(), vec![(0, 6), (1, 5)]);
+/// ```
+pub fn check_pairs(seq: &[RNA], structure: &[DotBracket]) -> Result<Vec<(usize, usize)>, SeqError> {
+    if seq.len() != structure.len() {
+        return Err(SeqErrorKind::StructureLengthError {
+            seq: seq.len(),
+            structure: structure.len(),
+        }
+        .into());
+    }
+
+    let pairs = dot_bracket_pairs(structure)?;
+    for &(i, j) in &pairs {
+        if !is_canonical_pair(&seq[i], &seq[j]) {
+            return Err(SeqErrorKind::NonCanonicalPairError { left: i, right: j }.into());
+        }
+    }
+
+    Ok(pairs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::any;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_cardinality() {
+        assert_eq!(DotBracket::cardinality(), DotBracket::variants().len());
+    }
+
+    #[test]
+    fn test_unbalanced() {
+        let err = parse_dot_bracket("(.))").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &SeqErrorKind::UnbalancedBracketError { position: 3 }
+        );
+
+        let err = parse_dot_bracket("..(<.>").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &SeqErrorKind::UnbalancedBracketError { position: 2 }
+        );
+
+        assert_eq!(parse_dot_bracket("....").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_check_pairs() {
+        let seq: Vec<RNA> = b"GCAAAGU"
+            .iter()
+            .map(|b| RNA::try_from(b).unwrap())
+            .collect();
+        let ss: Vec<DotBracket> = b"((...))"
+            .iter()
+            .map(|b| DotBracket::try_from(b).unwrap())
+            .collect();
+
+        // G-U wobble, then C-G.
+        assert_eq!(check_pairs(&seq, &ss).unwrap(), vec![(0, 6), (1, 5)]);
+
+        let ss: Vec<DotBracket> = b"(.(.))."
+            .iter()
+            .map(|b| DotBracket::try_from(b).unwrap())
+            .collect();
+        let err = check_pairs(&seq, &ss).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &SeqErrorKind::NonCanonicalPairError { left: 0, right: 5 }
+        );
+
+        assert!(check_pairs(&seq[1..], &ss).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_from_u8_doesnt_crash(c in any::<u8>()) {
+            let _dummy = DotBracket::try_from(c);
+        }
+
+        #[test]
+        fn test_from_to_u8_recovers_original(b in select(DotBracket::variants())) {
+            assert_eq!(DotBracket::try_from(u8::from(b)).unwrap(), b);
+        }
+
+        #[test]
+        fn test_canonical_pair_is_symmetric(
+            a in select(RNA::variants()),
+            b in select(RNA::variants()),
+        ) {
+            assert_eq!(is_canonical_pair(&a, &b), is_canonical_pair(&b, &a));
+        }
+
+        #[test]
+        fn test_parse_doesnt_crash(s in "[.()\\[\\]{}<>]{0,20}") {
+            let _dummy = parse_dot_bracket(&s);
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! Protein secondary structure alphabets, as assigned by DSSP.
+use std::convert::TryFrom;
+
+use crate::alphabet::Alphabet;
+use crate::errors::{Position, SeqError, SeqErrorKind};
+use crate::matcher::Match;
+
+/// The eight secondary structure states assigned by DSSP.
+///
+/// Coil is read from `C`, `-` or a space, and is written as `C`.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::{DSSP3, DSSP8};
+/// use std::convert::TryFrom;
+///
+/// let ss: Vec<DSSP3> = b"HGIEBTS-"
+///     .iter()
+///     .map(|b| DSSP8::try_from(b).map(DSSP3::from))
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+///
+/// let s: String = ss.iter().map(char::from).collect();
+/// assert_eq!(s, "HHHEECCC");
+/// ```
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+pub enum DSSP8 {
+    /// Alpha helix.
+    H,
+    /// 3-10 helix.
+    G,
+    /// Pi helix.
+    I,
+    /// Extended strand in a beta ladder.
+    E,
+    /// Isolated beta bridge.
+    B,
+    /// Hydrogen bonded turn.
+    T,
+    /// Bend.
+    S,
+    /// Coil, i.e. none of the above.
+    C,
+}
+
+impl Alphabet for DSSP8 {
+    /// The number of letters in this alphabet.
+    fn cardinality() -> usize {
+        8
+    }
+
+    fn rank(&self) -> usize {
+        *self as usize
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(r < Self::cardinality());
+        std::mem::transmute::<u8, Self>(r as u8)
+    }
+
+    /// Returns a Vec of all of the Enum variants.
+    fn variants() -> Vec<Self> {
+        use self::DSSP8::*;
+        vec![H, G, I, E, B, T, S, C]
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&u8> for DSSP8 {
+        type Error = SeqError;
+        fn try_from(base: &u8) -> Result<Self, Self::Error> {
+            match base.to_ascii_uppercase() {
+                b'H' => Ok(DSSP8::H),
+                b'G' => Ok(DSSP8::G),
+                b'I' => Ok(DSSP8::I),
+                b'E' => Ok(DSSP8::E),
+                b'B' => Ok(DSSP8::B),
+                b'T' => Ok(DSSP8::T),
+                b'S' => Ok(DSSP8::S),
+                b'C' | b'-' | b' ' => Ok(DSSP8::C),
+                b => Err(SeqErrorKind::AlphabetReadError {
+                    base: b as char,
+                    position: Position(None),
+                }
+                .into()),
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&char> for DSSP8 {
+        type Error = SeqError;
+        fn try_from(base: &char) -> Result<Self, Self::Error> {
+            crate::utils::char_to_byte(base).and_then(|b| Self::try_from(&b))
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&DSSP8> for u8 {
+        fn from(base: &DSSP8) -> Self {
+            match base {
+                DSSP8::H => b'H',
+                DSSP8::G => b'G',
+                DSSP8::I => b'I',
+                DSSP8::E => b'E',
+                DSSP8::B => b'B',
+                DSSP8::T => b'T',
+                DSSP8::S => b'S',
+                DSSP8::C => b'C',
+            }
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&DSSP8> for char {
+        fn from(base: &DSSP8) -> Self {
+            u8::from(base) as char
+        }
+    }
+}
+
+impl std::fmt::Display for DSSP8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+impl Match<DSSP8> for DSSP8 {
+    fn matches(&self, other: &DSSP8) -> bool {
+        self == other
+    }
+}
+
+/// The three state helix, strand, and coil secondary structure alphabet.
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+pub enum DSSP3 {
+    /// Helix.
+    H,
+    /// Strand.
+    E,
+    /// Coil.
+    C,
+}
+
+impl Alphabet for DSSP3 {
+    /// The number of letters in this alphabet.
+    fn cardinality() -> usize {
+        3
+    }
+
+    fn rank(&self) -> usize {
+        *self as usize
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(r < Self::cardinality());
+        std::mem::transmute::<u8, Self>(r as u8)
+    }
+
+    /// Returns a Vec of all of the Enum variants.
+    fn variants() -> Vec<Self> {
+        vec![DSSP3::H, DSSP3::E, DSSP3::C]
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&u8> for DSSP3 {
+        type Error = SeqError;
+        fn try_from(base: &u8) -> Result<Self, Self::Error> {
+            match base.to_ascii_uppercase() {
+                b'H' => Ok(DSSP3::H),
+                b'E' => Ok(DSSP3::E),
+                b'C' | b'-' | b' ' => Ok(DSSP3::C),
+                b => Err(SeqErrorKind::AlphabetReadError {
+                    base: b as char,
+                    position: Position(None),
+                }
+                .into()),
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&char> for DSSP3 {
+        type Error = SeqError;
+        fn try_from(base: &char) -> Result<Self, Self::Error> {
+            crate::utils::char_to_byte(base).and_then(|b| Self::try_from(&b))
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&DSSP3> for u8 {
+        fn from(base: &DSSP3) -> Self {
+            match base {
+                DSSP3::H => b'H',
+                DSSP3::E => b'E',
+                DSSP3::C => b'C',
+            }
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&DSSP3> for char {
+        fn from(base: &DSSP3) -> Self {
+            u8::from(base) as char
+        }
+    }
+}
+
+impl std::fmt::Display for DSSP3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+impl Match<DSSP3> for DSSP3 {
+    fn matches(&self, other: &DSSP3) -> bool {
+        self == other
+    }
+}
+
+from_borrowed! {
+    impl From<&DSSP8> for DSSP3 {
+        fn from(base: &DSSP8) -> Self {
+            // G and I are helices, B is a strand, turns and bends are coil.
+            match base {
+                DSSP8::H | DSSP8::G | DSSP8::I => DSSP3::H,
+                DSSP8::E | DSSP8::B => DSSP3::E,
+                DSSP8::T | DSSP8::S | DSSP8::C => DSSP3::C,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::any;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_cardinality() {
+        assert_eq!(DSSP8::cardinality(), DSSP8::variants().len());
+        assert_eq!(DSSP3::cardinality(), DSSP3::variants().len());
+    }
+
+    #[test]
+    fn test_coil() {
+        for c in b"C- " {
+            assert_eq!(DSSP8::try_from(c).unwrap(), DSSP8::C);
+            assert_eq!(DSSP3::try_from(c).unwrap(), DSSP3::C);
+        }
+        assert!(DSSP3::try_from(b'G').is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_from_u8_doesnt_crash(c in any::<u8>()) {
+            let _dummy = DSSP8::try_from(c);
+            let _dummy = DSSP3::try_from(c);
+        }
+
+        #[test]
+        fn test_from_to_u8_recovers_original(b in select(DSSP8::variants())) {
+            assert_eq!(DSSP8::try_from(u8::from(b)).unwrap(), b);
+        }
+
+        #[test]
+        fn test_dssp3_from_to_u8_recovers_original(b in select(DSSP3::variants())) {
+            assert_eq!(DSSP3::try_from(u8::from(b)).unwrap(), b);
+        }
+
+        // The three state letters mean the same thing in both alphabets.
+        #[test]
+        fn test_reduction_keeps_shared_letters(b in select(DSSP3::variants())) {
+            assert_eq!(DSSP3::from(DSSP8::try_from(u8::from(b)).unwrap()), b);
+        }
+    }
+}
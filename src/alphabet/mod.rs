@@ -19,14 +19,34 @@
 
 
 mod aa;
+mod aa20;
+mod aa22;
 mod dna;
 mod dna4;
+mod dna5;
+mod dotbracket;
+mod dssp;
+mod moddna;
+mod reduced;
+mod rna;
+mod rna4;
 mod tables;
 mod tags;
 
-pub use self::aa::AA;
+pub use self::aa::{parse_three_letter, to_three_letter, AA};
+pub use self::aa20::AA20;
+pub use self::aa22::AA22;
 pub use self::dna::DNA;
 pub use self::dna4::DNA4;
+pub use self::dna5::DNA5;
+pub use self::dotbracket::{
+    check_pairs, dot_bracket_pairs, is_canonical_pair, parse_dot_bracket, DotBracket,
+};
+pub use self::dssp::{DSSP3, DSSP8};
+pub use self::moddna::{ModDNA, Modification};
+pub use self::reduced::{Dayhoff6, Murphy10, SEB14};
+pub use self::rna::RNA;
+pub use self::rna4::RNA4;
 pub use self::tags::CodonTag;
 
 pub trait Alphabet: Sized {
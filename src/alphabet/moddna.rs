@@ -0,0 +1,372 @@
+//! DNA with epigenetic base modifications.
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::alphabet::Alphabet;
+use crate::alphabet::DNA4;
+use crate::complement::Complement;
+use crate::errors::{SeqError, SeqErrorKind};
+use crate::kmer::Strand;
+use crate::matcher::Match;
+
+/// A chemical modification of a canonical base.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+pub enum Modification {
+    /// 5-methylcytosine (5mC).
+    MethylC5,
+    /// 5-hydroxymethylcytosine (5hmC).
+    HydroxymethylC5,
+    /// N6-methyladenine (6mA).
+    MethylA6,
+    /// N4-methylcytosine (4mC).
+    MethylC4,
+}
+
+impl Modification {
+    /// The unmodified base that this modification is made to.
+    pub fn canonical(&self) -> DNA4 {
+        match self {
+            Modification::MethylC5 => DNA4::C,
+            Modification::HydroxymethylC5 => DNA4::C,
+            Modification::MethylA6 => DNA4::A,
+            Modification::MethylC4 => DNA4::C,
+        }
+    }
+
+    /// The code used for this modification in SAM `MM` tags.
+    ///
+    /// 4mC has no single letter code, so the ChEBI identifier is used.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Modification::MethylC5 => "m",
+            Modification::HydroxymethylC5 => "h",
+            Modification::MethylA6 => "a",
+            Modification::MethylC4 => "21839",
+        }
+    }
+
+    /// Parse a SAM `MM` tag modification code.
+    pub fn from_code(code: &str) -> Result<Self, SeqError> {
+        match code {
+            "m" => Ok(Modification::MethylC5),
+            "h" => Ok(Modification::HydroxymethylC5),
+            "a" => Ok(Modification::MethylA6),
+            "21839" => Ok(Modification::MethylC4),
+            c => Err(SeqErrorKind::ModificationReadError {
+                code: c.to_string(),
+            }
+            .into()),
+        }
+    }
+
+    fn variants() -> [Modification; 4] {
+        [
+            Modification::MethylC5,
+            Modification::HydroxymethylC5,
+            Modification::MethylA6,
+            Modification::MethylC4,
+        ]
+    }
+}
+
+/// A DNA4 base, possibly carrying a modification on either strand.
+///
+/// Modifications are strand-aware. A modification on the [`Forward`] strand
+/// is made to this base, and one on the [`Reverse`] strand is made to the
+/// paired base on the opposite strand. E.G. a `G` methylated on the reverse
+/// strand is paired with a 5mC. Complementing a base flips the strand that
+/// its modification is on, so reverse complementing a sequence keeps
+/// modifications on the right molecule.
+///
+/// The text representation follows SAM `MM` tags, with the base followed by
+/// the strand and the modification code, e.g. `C+m` or `G-m`.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::{ModDNA, Modification, DNA4};
+/// use seqrs::complement::Complement;
+/// use seqrs::kmer::Strand;
+/// use seqrs::matcher::Match;
+///
+/// let mc = ModDNA::modified(Modification::MethylC5, Strand::Forward);
+/// assert_eq!(mc.base(), DNA4::C);
+/// assert!(mc.matches(&DNA4::C));
+///
+/// let comp = mc.complement();
+/// assert_eq!(comp.base(), DNA4::G);
+/// assert_eq!(comp.strand(), Some(Strand::Reverse));
+/// assert_eq!(comp.to_string(), "G-m");
+///
+/// assert_eq!("G-m".parse::<ModDNA>().unwrap(), comp);
+/// ```
+///
+/// [`Forward`]: ../kmer/enum.Strand.html#variant.Forward
+/// [`Reverse`]: ../kmer/enum.Strand.html#variant.Reverse
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub struct ModDNA {
+    base: DNA4,
+    modification: Option<Modification>,
+}
+
+impl ModDNA {
+    /// An unmodified base.
+    pub fn canonical(base: DNA4) -> Self {
+        ModDNA {
+            base: base,
+            modification: None,
+        }
+    }
+
+    /// A modified base, with the modification on the given strand.
+    pub fn modified(modification: Modification, strand: Strand) -> Self {
+        let base = match strand {
+            Strand::Forward => modification.canonical(),
+            Strand::Reverse => modification.canonical().complement(),
+        };
+
+        ModDNA {
+            base: base,
+            modification: Some(modification),
+        }
+    }
+
+    /// The underlying base, without the modification.
+    pub fn base(&self) -> DNA4 {
+        self.base
+    }
+
+    pub fn modification(&self) -> Option<Modification> {
+        self.modification
+    }
+
+    /// The strand carrying the modification, or `None` if the base is
+    /// unmodified.
+    pub fn strand(&self) -> Option<Strand> {
+        self.modification.map(|m| {
+            if m.canonical() == self.base {
+                Strand::Forward
+            } else {
+                Strand::Reverse
+            }
+        })
+    }
+
+    pub fn is_modified(&self) -> bool {
+        self.modification.is_some()
+    }
+}
+
+impl Alphabet for ModDNA {
+    /// The four canonical bases, then each modification on each strand.
+    fn cardinality() -> usize {
+        4 + 2 * Modification::variants().len()
+    }
+
+    fn rank(&self) -> usize {
+        match self.modification {
+            None => self.base.rank(),
+            Some(m) => {
+                let strand = match self.strand() {
+                    Some(Strand::Reverse) => 1,
+                    _ => 0,
+                };
+                4 + 2 * (m as usize) + strand
+            }
+        }
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(r < Self::cardinality());
+        if r < 4 {
+            ModDNA::canonical(DNA4::from_rank_unsafe(r))
+        } else {
+            let m = Modification::variants()[(r - 4) / 2];
+            let strand = if (r - 4) % 2 == 0 {
+                Strand::Forward
+            } else {
+                Strand::Reverse
+            };
+            ModDNA::modified(m, strand)
+        }
+    }
+
+    /// Returns a Vec of all of the variants.
+    fn variants() -> Vec<Self> {
+        (0..Self::cardinality())
+            .map(|r| unsafe { Self::from_rank_unsafe(r) })
+            .collect()
+    }
+}
+
+impl FromStr for ModDNA {
+    type Err = SeqError;
+
+    /// Parse a base, optionally followed by a strand (`+` or `-`) and an
+    /// `MM` tag modification code.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let base = chars
+            .next()
+            .ok_or_else(|| {
+                SeqError::from(SeqErrorKind::ModificationReadError {
+                    code: s.to_string(),
+                })
+            })
+            .and_then(DNA4::try_from)?;
+
+        let strand = match chars.next() {
+            None => return Ok(ModDNA::canonical(base)),
+            Some('+') => Strand::Forward,
+            Some('-') => Strand::Reverse,
+            Some(_) => {
+                return Err(SeqErrorKind::ModificationReadError {
+                    code: s.to_string(),
+                }
+                .into())
+            }
+        };
+
+        let modified = ModDNA::modified(Modification::from_code(chars.as_str())?, strand);
+        if modified.base == base {
+            Ok(modified)
+        } else {
+            Err(SeqErrorKind::ModificationReadError {
+                code: s.to_string(),
+            }
+            .into())
+        }
+    }
+}
+
+impl std::fmt::Display for ModDNA {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match (self.modification, self.strand()) {
+            (Some(m), Some(Strand::Forward)) => write!(f, "{}+{}", self.base, m.code()),
+            (Some(m), Some(Strand::Reverse)) => write!(f, "{}-{}", self.base, m.code()),
+            _ => write!(f, "{}", self.base),
+        }
+    }
+}
+
+impl Complement for ModDNA {
+    type Compl = ModDNA;
+
+    /// Complements the base, moving any modification to the other strand.
+    fn complement(&self) -> Self::Compl {
+        ModDNA {
+            base: self.base.complement(),
+            modification: self.modification,
+        }
+    }
+}
+
+/// Bases match if they have the same underlying base, and either one is
+/// unmodified or they have the same modification.
+impl Match<ModDNA> for ModDNA {
+    fn matches(&self, other: &ModDNA) -> bool {
+        if self.base != other.base {
+            return false;
+        }
+
+        match (self.modification, other.modification) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        }
+    }
+}
+
+impl Match<DNA4> for ModDNA {
+    fn matches(&self, other: &DNA4) -> bool {
+        &self.base == other
+    }
+}
+
+impl Match<ModDNA> for DNA4 {
+    fn matches(&self, other: &ModDNA) -> bool {
+        self == &other.base
+    }
+}
+
+from_borrowed! {
+    impl From<&ModDNA> for DNA4 {
+        fn from(base: &ModDNA) -> Self {
+            base.base
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&DNA4> for ModDNA {
+        fn from(base: &DNA4) -> Self {
+            ModDNA::canonical(*base)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_cardinality() {
+        assert_eq!(ModDNA::cardinality(), ModDNA::variants().len());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let ma = ModDNA::modified(Modification::MethylA6, Strand::Reverse);
+        assert_eq!("T-a".parse::<ModDNA>().unwrap(), ma);
+        assert_eq!(
+            "C+21839".parse::<ModDNA>().unwrap().modification(),
+            Some(Modification::MethylC4)
+        );
+        assert_eq!("t".parse::<ModDNA>().unwrap(), ModDNA::canonical(DNA4::T));
+
+        // The base must be the one modified on the given strand.
+        assert!("A+m".parse::<ModDNA>().is_err());
+        assert!("C-m".parse::<ModDNA>().is_err());
+        assert!("C+x".parse::<ModDNA>().is_err());
+        assert!("".parse::<ModDNA>().is_err());
+    }
+
+    #[test]
+    fn test_matches() {
+        let mc = ModDNA::modified(Modification::MethylC5, Strand::Forward);
+        let hmc = ModDNA::modified(Modification::HydroxymethylC5, Strand::Forward);
+        let c = ModDNA::canonical(DNA4::C);
+
+        assert!(mc.matches(&c));
+        assert!(c.matches(&hmc));
+        assert!(!mc.matches(&hmc));
+        assert!(!mc.matches(&DNA4::G));
+        assert!(mc.complement().matches(&DNA4::G));
+    }
+
+    proptest! {
+        #[test]
+        fn test_rank_recovers_original(b in select(ModDNA::variants())) {
+            assert_eq!(ModDNA::from_rank(b.rank()).unwrap(), b);
+        }
+
+        #[test]
+        fn test_string_recovers_original(b in select(ModDNA::variants())) {
+            assert_eq!(b.to_string().parse::<ModDNA>().unwrap(), b);
+        }
+
+        #[test]
+        fn test_complement_twice_is_original(b in select(ModDNA::variants())) {
+            assert_eq!(b.complement().complement(), b);
+            assert_eq!(b.complement().modification(), b.modification());
+        }
+
+        // Projecting to DNA4 commutes with complementing.
+        #[test]
+        fn test_complement_agrees_with_dna4(b in select(ModDNA::variants())) {
+            assert_eq!(DNA4::from(b.complement()), DNA4::from(b).complement());
+            assert!(b.matches(&DNA4::from(b)));
+        }
+    }
+}
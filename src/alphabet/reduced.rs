@@ -0,0 +1,568 @@
+//! Reduced amino acid alphabets.
+//!
+//! Reduced alphabets group amino acids with similar physicochemical
+//! properties, which makes kmer searches for remote homologues and
+//! composition analyses more sensitive.
+//! Each group is represented by the letter of one of its members.
+//!
+//! Every alphabet includes an `X` letter for residues that can't be placed
+//! in a single group. Redundant [`AA`] codes are projected onto a group if
+//! all of the residues they represent fall into it (e.g. `B` is `D` or `N`),
+//! and onto `X` otherwise. The nonstandard residues selenocysteine (`U`) and
+//! pyrrolysine (`O`) are grouped with cysteine and lysine respectively.
+//!
+//! # Examples:
+//!
+//! ```
+//! use seqrs::alphabet::{Dayhoff6, Murphy10, SEB14, AA};
+//!
+//! assert_eq!(Dayhoff6::from(AA::Q), Dayhoff6::D);
+//! assert_eq!(Murphy10::from(AA::Y), Murphy10::F);
+//!
+//! // B is D or N, which are in different SE-B(14) groups.
+//! assert_eq!(SEB14::from(AA::B), SEB14::X);
+//! assert_eq!(Dayhoff6::from(AA::B), Dayhoff6::D);
+//! ```
+//!
+//! [`AA`]: enum.AA.html
+use std::convert::TryFrom;
+
+use crate::alphabet::Alphabet;
+use crate::alphabet::AA;
+use crate::errors::{Position, SeqError, SeqErrorKind};
+use crate::matcher::Match;
+
+/// The six Dayhoff groups.
+///
+/// | Letter | Residues  |
+/// |--------|-----------|
+/// | `C`    | C         |
+/// | `A`    | A G P S T |
+/// | `D`    | D E N Q   |
+/// | `H`    | H K R     |
+/// | `I`    | I L M V   |
+/// | `F`    | F W Y     |
+/// | `X`    | Any       |
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+pub enum Dayhoff6 {
+    C,
+    A,
+    D,
+    H,
+    I,
+    F,
+    X,
+}
+
+impl Alphabet for Dayhoff6 {
+    /// The number of letters in this alphabet.
+    fn cardinality() -> usize {
+        7
+    }
+
+    fn rank(&self) -> usize {
+        *self as usize
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(r < Self::cardinality());
+        std::mem::transmute::<u8, Self>(r as u8)
+    }
+
+    /// Returns a Vec of all of the Enum variants.
+    fn variants() -> Vec<Self> {
+        use self::Dayhoff6::*;
+        vec![C, A, D, H, I, F, X]
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&u8> for Dayhoff6 {
+        type Error = SeqError;
+        fn try_from(base: &u8) -> Result<Self, Self::Error> {
+            match base.to_ascii_uppercase() {
+                b'C' => Ok(Dayhoff6::C),
+                b'A' => Ok(Dayhoff6::A),
+                b'D' => Ok(Dayhoff6::D),
+                b'H' => Ok(Dayhoff6::H),
+                b'I' => Ok(Dayhoff6::I),
+                b'F' => Ok(Dayhoff6::F),
+                b'X' => Ok(Dayhoff6::X),
+                b => Err(SeqErrorKind::AlphabetReadError {
+                    base: b as char,
+                    position: Position(None),
+                }
+                .into()),
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&char> for Dayhoff6 {
+        type Error = SeqError;
+        fn try_from(base: &char) -> Result<Self, Self::Error> {
+            crate::utils::char_to_byte(base).and_then(|b| Self::try_from(&b))
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&Dayhoff6> for u8 {
+        fn from(base: &Dayhoff6) -> Self {
+            match base {
+                Dayhoff6::C => b'C',
+                Dayhoff6::A => b'A',
+                Dayhoff6::D => b'D',
+                Dayhoff6::H => b'H',
+                Dayhoff6::I => b'I',
+                Dayhoff6::F => b'F',
+                Dayhoff6::X => b'X',
+            }
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&Dayhoff6> for char {
+        fn from(base: &Dayhoff6) -> Self {
+            u8::from(base) as char
+        }
+    }
+}
+
+impl std::fmt::Display for Dayhoff6 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+impl Match<Dayhoff6> for Dayhoff6 {
+    fn matches(&self, other: &Dayhoff6) -> bool {
+        match (self, other) {
+            (Dayhoff6::X, _) | (_, Dayhoff6::X) => true,
+            (a, b) => a == b,
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&AA> for Dayhoff6 {
+        fn from(base: &AA) -> Self {
+            match base {
+                AA::C | AA::U => Dayhoff6::C,
+                AA::A | AA::G | AA::P | AA::S | AA::T => Dayhoff6::A,
+                AA::D | AA::E | AA::N | AA::Q | AA::B | AA::Z => Dayhoff6::D,
+                AA::H | AA::K | AA::R | AA::O => Dayhoff6::H,
+                AA::I | AA::L | AA::M | AA::V | AA::J => Dayhoff6::I,
+                AA::F | AA::W | AA::Y => Dayhoff6::F,
+                AA::X => Dayhoff6::X,
+            }
+        }
+    }
+}
+
+/// The ten groups of Murphy, Wallqvist and Levy (2000).
+///
+/// | Letter | Residues |
+/// |--------|----------|
+/// | `L`    | L V I M  |
+/// | `C`    | C        |
+/// | `A`    | A        |
+/// | `G`    | G        |
+/// | `S`    | S T      |
+/// | `P`    | P        |
+/// | `F`    | F Y W    |
+/// | `E`    | E D N Q  |
+/// | `K`    | K R      |
+/// | `H`    | H        |
+/// | `X`    | Any      |
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+pub enum Murphy10 {
+    L,
+    C,
+    A,
+    G,
+    S,
+    P,
+    F,
+    E,
+    K,
+    H,
+    X,
+}
+
+impl Alphabet for Murphy10 {
+    /// The number of letters in this alphabet.
+    fn cardinality() -> usize {
+        11
+    }
+
+    fn rank(&self) -> usize {
+        *self as usize
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(r < Self::cardinality());
+        std::mem::transmute::<u8, Self>(r as u8)
+    }
+
+    /// Returns a Vec of all of the Enum variants.
+    fn variants() -> Vec<Self> {
+        use self::Murphy10::*;
+        vec![L, C, A, G, S, P, F, E, K, H, X]
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&u8> for Murphy10 {
+        type Error = SeqError;
+        fn try_from(base: &u8) -> Result<Self, Self::Error> {
+            match base.to_ascii_uppercase() {
+                b'L' => Ok(Murphy10::L),
+                b'C' => Ok(Murphy10::C),
+                b'A' => Ok(Murphy10::A),
+                b'G' => Ok(Murphy10::G),
+                b'S' => Ok(Murphy10::S),
+                b'P' => Ok(Murphy10::P),
+                b'F' => Ok(Murphy10::F),
+                b'E' => Ok(Murphy10::E),
+                b'K' => Ok(Murphy10::K),
+                b'H' => Ok(Murphy10::H),
+                b'X' => Ok(Murphy10::X),
+                b => Err(SeqErrorKind::AlphabetReadError {
+                    base: b as char,
+                    position: Position(None),
+                }
+                .into()),
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&char> for Murphy10 {
+        type Error = SeqError;
+        fn try_from(base: &char) -> Result<Self, Self::Error> {
+            crate::utils::char_to_byte(base).and_then(|b| Self::try_from(&b))
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&Murphy10> for u8 {
+        fn from(base: &Murphy10) -> Self {
+            match base {
+                Murphy10::L => b'L',
+                Murphy10::C => b'C',
+                Murphy10::A => b'A',
+                Murphy10::G => b'G',
+                Murphy10::S => b'S',
+                Murphy10::P => b'P',
+                Murphy10::F => b'F',
+                Murphy10::E => b'E',
+                Murphy10::K => b'K',
+                Murphy10::H => b'H',
+                Murphy10::X => b'X',
+            }
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&Murphy10> for char {
+        fn from(base: &Murphy10) -> Self {
+            u8::from(base) as char
+        }
+    }
+}
+
+impl std::fmt::Display for Murphy10 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+impl Match<Murphy10> for Murphy10 {
+    fn matches(&self, other: &Murphy10) -> bool {
+        match (self, other) {
+            (Murphy10::X, _) | (_, Murphy10::X) => true,
+            (a, b) => a == b,
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&AA> for Murphy10 {
+        fn from(base: &AA) -> Self {
+            match base {
+                AA::L | AA::V | AA::I | AA::M | AA::J => Murphy10::L,
+                AA::C | AA::U => Murphy10::C,
+                AA::A => Murphy10::A,
+                AA::G => Murphy10::G,
+                AA::S | AA::T => Murphy10::S,
+                AA::P => Murphy10::P,
+                AA::F | AA::Y | AA::W => Murphy10::F,
+                AA::E | AA::D | AA::N | AA::Q | AA::B | AA::Z => Murphy10::E,
+                AA::K | AA::R | AA::O => Murphy10::K,
+                AA::H => Murphy10::H,
+                AA::X => Murphy10::X,
+            }
+        }
+    }
+}
+
+/// The SE-B(14) alphabet of Peterson et al. (2009).
+///
+/// | Letter | Residues |
+/// |--------|----------|
+/// | `A`    | A        |
+/// | `C`    | C        |
+/// | `D`    | D        |
+/// | `E`    | E Q      |
+/// | `F`    | F Y      |
+/// | `G`    | G        |
+/// | `H`    | H        |
+/// | `I`    | I V      |
+/// | `K`    | K R      |
+/// | `L`    | L M      |
+/// | `N`    | N        |
+/// | `P`    | P        |
+/// | `S`    | S T      |
+/// | `W`    | W        |
+/// | `X`    | Any      |
+///
+/// Note that `B` (`D` or `N`) and `J` (`I` or `L`) span two groups, so they
+/// are projected onto `X`.
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+pub enum SEB14 {
+    A,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    K,
+    L,
+    N,
+    P,
+    S,
+    W,
+    X,
+}
+
+impl Alphabet for SEB14 {
+    /// The number of letters in this alphabet.
+    fn cardinality() -> usize {
+        15
+    }
+
+    fn rank(&self) -> usize {
+        *self as usize
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(r < Self::cardinality());
+        std::mem::transmute::<u8, Self>(r as u8)
+    }
+
+    /// Returns a Vec of all of the Enum variants.
+    fn variants() -> Vec<Self> {
+        use self::SEB14::*;
+        vec![A, C, D, E, F, G, H, I, K, L, N, P, S, W, X]
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&u8> for SEB14 {
+        type Error = SeqError;
+        fn try_from(base: &u8) -> Result<Self, Self::Error> {
+            match base.to_ascii_uppercase() {
+                b'A' => Ok(SEB14::A),
+                b'C' => Ok(SEB14::C),
+                b'D' => Ok(SEB14::D),
+                b'E' => Ok(SEB14::E),
+                b'F' => Ok(SEB14::F),
+                b'G' => Ok(SEB14::G),
+                b'H' => Ok(SEB14::H),
+                b'I' => Ok(SEB14::I),
+                b'K' => Ok(SEB14::K),
+                b'L' => Ok(SEB14::L),
+                b'N' => Ok(SEB14::N),
+                b'P' => Ok(SEB14::P),
+                b'S' => Ok(SEB14::S),
+                b'W' => Ok(SEB14::W),
+                b'X' => Ok(SEB14::X),
+                b => Err(SeqErrorKind::AlphabetReadError {
+                    base: b as char,
+                    position: Position(None),
+                }
+                .into()),
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&char> for SEB14 {
+        type Error = SeqError;
+        fn try_from(base: &char) -> Result<Self, Self::Error> {
+            crate::utils::char_to_byte(base).and_then(|b| Self::try_from(&b))
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&SEB14> for u8 {
+        fn from(base: &SEB14) -> Self {
+            match base {
+                SEB14::A => b'A',
+                SEB14::C => b'C',
+                SEB14::D => b'D',
+                SEB14::E => b'E',
+                SEB14::F => b'F',
+                SEB14::G => b'G',
+                SEB14::H => b'H',
+                SEB14::I => b'I',
+                SEB14::K => b'K',
+                SEB14::L => b'L',
+                SEB14::N => b'N',
+                SEB14::P => b'P',
+                SEB14::S => b'S',
+                SEB14::W => b'W',
+                SEB14::X => b'X',
+            }
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&SEB14> for char {
+        fn from(base: &SEB14) -> Self {
+            u8::from(base) as char
+        }
+    }
+}
+
+impl std::fmt::Display for SEB14 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+impl Match<SEB14> for SEB14 {
+    fn matches(&self, other: &SEB14) -> bool {
+        match (self, other) {
+            (SEB14::X, _) | (_, SEB14::X) => true,
+            (a, b) => a == b,
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&AA> for SEB14 {
+        fn from(base: &AA) -> Self {
+            match base {
+                AA::A => SEB14::A,
+                AA::C | AA::U => SEB14::C,
+                AA::D => SEB14::D,
+                AA::E | AA::Q | AA::Z => SEB14::E,
+                AA::F | AA::Y => SEB14::F,
+                AA::G => SEB14::G,
+                AA::H => SEB14::H,
+                AA::I | AA::V => SEB14::I,
+                AA::K | AA::R | AA::O => SEB14::K,
+                AA::L | AA::M => SEB14::L,
+                AA::N => SEB14::N,
+                AA::P => SEB14::P,
+                AA::S | AA::T => SEB14::S,
+                AA::W => SEB14::W,
+                AA::B | AA::J | AA::X => SEB14::X,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::any;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_cardinality() {
+        assert_eq!(Dayhoff6::cardinality(), Dayhoff6::variants().len());
+        assert_eq!(Murphy10::cardinality(), Murphy10::variants().len());
+        assert_eq!(SEB14::cardinality(), SEB14::variants().len());
+    }
+
+    #[test]
+    fn test_groups() {
+        // Every group (besides X) should contain at least one residue.
+        let aas = AA::variants();
+        for b in Dayhoff6::variants() {
+            assert!(aas.iter().any(|a| Dayhoff6::from(a) == b));
+        }
+        for b in Murphy10::variants() {
+            assert!(aas.iter().any(|a| Murphy10::from(a) == b));
+        }
+        for b in SEB14::variants() {
+            assert!(aas.iter().any(|a| SEB14::from(a) == b));
+        }
+
+        assert_eq!(SEB14::from(AA::J), SEB14::X);
+        assert_eq!(SEB14::from(AA::Z), SEB14::E);
+        assert_eq!(Murphy10::from(AA::J), Murphy10::L);
+    }
+
+    proptest! {
+        #[test]
+        fn test_from_u8_doesnt_crash(c in any::<u8>()) {
+            let _dummy = Dayhoff6::try_from(c);
+            let _dummy = Murphy10::try_from(c);
+            let _dummy = SEB14::try_from(c);
+        }
+
+        #[test]
+        fn test_from_to_u8_recovers_original(
+            d in select(Dayhoff6::variants()),
+            m in select(Murphy10::variants()),
+            s in select(SEB14::variants()),
+        ) {
+            assert_eq!(Dayhoff6::try_from(u8::from(d)).unwrap(), d);
+            assert_eq!(Murphy10::try_from(u8::from(m)).unwrap(), m);
+            assert_eq!(SEB14::try_from(u8::from(s)).unwrap(), s);
+        }
+
+        // The representative letter of a group should be a member of it.
+        #[test]
+        fn test_representative_in_group(
+            d in select(Dayhoff6::variants()),
+            m in select(Murphy10::variants()),
+            s in select(SEB14::variants()),
+        ) {
+            assert_eq!(Dayhoff6::from(AA::try_from(u8::from(d)).unwrap()), d);
+            assert_eq!(Murphy10::from(AA::try_from(u8::from(m)).unwrap()), m);
+            assert_eq!(SEB14::from(AA::try_from(u8::from(s)).unwrap()), s);
+        }
+
+        // Projecting matching residues should give matching groups.
+        #[test]
+        fn test_projection_preserves_matches(
+            a in select(AA::variants()),
+            b in select(AA::variants()),
+        ) {
+            if a.matches(&b) {
+                assert!(Dayhoff6::from(a).matches(&Dayhoff6::from(b)));
+                assert!(Murphy10::from(a).matches(&Murphy10::from(b)));
+                assert!(SEB14::from(a).matches(&SEB14::from(b)));
+            }
+        }
+    }
+}
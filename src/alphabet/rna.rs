@@ -0,0 +1,368 @@
+//! A fully redundant RNA alphabet.
+use std::convert::TryFrom;
+
+use crate::alphabet::Alphabet;
+use crate::alphabet::RedundantAlphabet;
+use crate::alphabet::{DNA, RNA4};
+use crate::complement::Complement;
+use crate::errors::{Position, SeqError, SeqErrorKind};
+use crate::matcher::Match;
+
+/// A fully redundant RNA alphabet represented as an enum.
+///
+/// The bit layout is the same as [`DNA`], with `U` taking the place of `T`.
+///
+/// [`DNA`]: enum.DNA.html
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum RNA {
+    A = 0b0001,
+    C = 0b0010,
+    M = 0b0011,
+    G = 0b0100,
+    R = 0b0101,
+    S = 0b0110,
+    V = 0b0111,
+    U = 0b1000,
+    W = 0b1001,
+    Y = 0b1010,
+    H = 0b1011,
+    K = 0b1100,
+    D = 0b1101,
+    B = 0b1110,
+    N = 0b1111,
+}
+
+impl RNA {
+    /// For redundant matches, returns a vec of each non-redundant
+    /// base that it contains.
+    pub(crate) fn redundant_matches(&self) -> Vec<Self> {
+        use super::RNA::*;
+        match self {
+            M => vec![A, C],
+            R => vec![A, G],
+            S => vec![C, G],
+            V => vec![A, C, G],
+            W => vec![A, U],
+            Y => vec![C, U],
+            H => vec![A, C, U],
+            K => vec![G, U],
+            D => vec![A, G, U],
+            B => vec![C, G, U],
+            N => vec![A, C, G, U],
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl Alphabet for RNA {
+    /// The number of letters in this alphabet.
+    fn cardinality() -> usize {
+        15
+    }
+
+    fn rank(&self) -> usize {
+        (*self as usize) - 1
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(r < Self::cardinality());
+        std::mem::transmute::<u8, Self>((r + 1) as u8)
+    }
+
+    /// Returns a Vec of all of the Enum variants.
+    fn variants() -> Vec<Self> {
+        vec![
+            RNA::A,
+            RNA::C,
+            RNA::M,
+            RNA::G,
+            RNA::R,
+            RNA::S,
+            RNA::V,
+            RNA::U,
+            RNA::W,
+            RNA::Y,
+            RNA::H,
+            RNA::K,
+            RNA::D,
+            RNA::B,
+            RNA::N,
+        ]
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&u8> for RNA {
+        type Error = SeqError;
+        fn try_from(base: &u8) -> Result<Self, Self::Error> {
+            match base.to_ascii_uppercase() {
+                b'A' => Ok(RNA::A),
+                b'C' => Ok(RNA::C),
+                b'M' => Ok(RNA::M),
+                b'G' => Ok(RNA::G),
+                b'R' => Ok(RNA::R),
+                b'S' => Ok(RNA::S),
+                b'V' => Ok(RNA::V),
+                b'U' => Ok(RNA::U),
+                b'W' => Ok(RNA::W),
+                b'Y' => Ok(RNA::Y),
+                b'H' => Ok(RNA::H),
+                b'K' => Ok(RNA::K),
+                b'D' => Ok(RNA::D),
+                b'B' => Ok(RNA::B),
+                b'N' => Ok(RNA::N),
+                b => Err(SeqErrorKind::AlphabetReadError {
+                    base: b as char,
+                    position: Position(None),
+                }
+                .into()),
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&char> for RNA {
+        type Error = SeqError;
+        fn try_from(base: &char) -> Result<Self, Self::Error> {
+            crate::utils::char_to_byte(base).and_then(|b| Self::try_from(&b))
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&RNA> for u8 {
+        fn from(base: &RNA) -> Self {
+            match base {
+                RNA::A => b'A',
+                RNA::C => b'C',
+                RNA::M => b'M',
+                RNA::G => b'G',
+                RNA::R => b'R',
+                RNA::S => b'S',
+                RNA::V => b'V',
+                RNA::U => b'U',
+                RNA::W => b'W',
+                RNA::Y => b'Y',
+                RNA::H => b'H',
+                RNA::K => b'K',
+                RNA::D => b'D',
+                RNA::B => b'B',
+                RNA::N => b'N',
+            }
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&RNA> for char {
+        fn from(base: &RNA) -> Self {
+            u8::from(base) as char
+        }
+    }
+}
+
+impl std::fmt::Display for RNA {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+impl Complement for RNA {
+    type Compl = RNA;
+    fn complement(&self) -> Self::Compl {
+        let a = *self as u8;
+        let comp = (a & 0b0001) << 3 | (a & 0b0010) << 1 | (a & 0b0100) >> 1 | (a & 0b1000) >> 3;
+
+        unsafe { std::mem::transmute::<u8, Self::Compl>(comp) }
+    }
+}
+
+impl Match<RNA> for RNA {
+    fn matches(&self, other: &RNA) -> bool {
+        let a = *self as u8;
+        let b = *other as u8;
+        (a & b) > 0
+    }
+}
+
+impl RedundantAlphabet for RNA {
+    fn union(&self, other: &Self) -> Self {
+        let a = *self as u8;
+        let b = *other as u8;
+        unsafe { std::mem::transmute::<u8, RNA>(a | b) }
+    }
+
+    fn intersection(&self, other: &Self) -> Option<Self> {
+        let a = *self as u8;
+        let b = *other as u8;
+        let inter = a & b;
+        if inter != 0 {
+            unsafe { Some(std::mem::transmute::<u8, RNA>(inter)) }
+        } else {
+            None
+        }
+    }
+
+    fn difference(&self, other: &Self) -> Option<Self> {
+        let a = *self as u8;
+        let b = *other as u8;
+        let inter = a & (!b);
+        if inter != 0 {
+            unsafe { Some(std::mem::transmute::<u8, RNA>(inter)) }
+        } else {
+            None
+        }
+    }
+
+    fn is_redundant(&self) -> bool {
+        match self {
+            RNA::A | RNA::C | RNA::G | RNA::U => false,
+            _ => true,
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&RNA4> for RNA {
+        fn from(base: &RNA4) -> Self {
+            let a = *base as u8;
+            let b: u8 = 0b0001 << a;
+
+            unsafe { std::mem::transmute::<u8, RNA>(b) }
+        }
+    }
+}
+
+// DNA and RNA share a bit layout, so conversions are just a relabelling.
+from_borrowed! {
+    impl From<&DNA> for RNA {
+        fn from(base: &DNA) -> Self {
+            unsafe { std::mem::transmute::<u8, RNA>(*base as u8) }
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&RNA> for DNA {
+        fn from(base: &RNA) -> Self {
+            unsafe { std::mem::transmute::<u8, DNA>(*base as u8) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complement::IntoReverseComplement;
+    use proptest::prelude::any;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_complement_vec() {
+        let d = vec![RNA::A, RNA::U, RNA::G, RNA::C];
+        let c: Vec<RNA> = d.iter().reverse_complement().collect();
+        assert_eq!(c, vec![RNA::G, RNA::C, RNA::A, RNA::U]);
+
+        assert_eq!(RNA::R.complement(), RNA::Y);
+        assert_eq!(RNA::N.complement(), RNA::N);
+    }
+
+    #[test]
+    fn test_match() {
+        assert!(RNA::A.matches(&RNA::N));
+        assert!(RNA::U.matches(&RNA::W));
+        assert!(RNA::R.matches(&RNA::S)); // AG == GC
+        assert!(RNA::A.doesnt_match(&RNA::U));
+        assert!(RNA::R.doesnt_match(&RNA::Y)); // AG != CU
+    }
+
+    #[test]
+    fn test_cardinality() {
+        assert_eq!(RNA::cardinality() as usize, RNA::variants().len());
+    }
+
+    #[test]
+    fn test_redundant_matches() {
+        for base in RNA::variants() {
+            for red_base in base.redundant_matches() {
+                assert!(base.matches(&red_base));
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_dna() {
+        assert_eq!(RNA::from(DNA::T), RNA::U);
+        assert_eq!(RNA::from(DNA::Y), RNA::Y);
+        assert_eq!(DNA::from(RNA::U), DNA::T);
+        assert_eq!(RNA::from(RNA4::U), RNA::U);
+    }
+
+    proptest! {
+        #[test]
+        fn test_from_u8_doesnt_crash(c in any::<u8>()) {
+            let _dummy = RNA::try_from(c);
+        }
+
+        #[test]
+        fn test_from_char_doesnt_crash(c in any::<char>()) {
+            let _dummy = RNA::try_from(c);
+        }
+
+        #[test]
+        fn test_from_to_u8_recovers_original(b in select(RNA::variants())) {
+            assert_eq!(RNA::try_from(u8::from(b)).unwrap(), b);
+            assert_eq!(
+                RNA::try_from(u8::from(b).to_ascii_lowercase()).unwrap(),
+                b
+            );
+        }
+
+        #[test]
+        fn test_from_to_char_recovers_original(b in select(RNA::variants())) {
+            assert_eq!(RNA::try_from(char::from(b)).unwrap(), b);
+            assert_eq!(
+                RNA::try_from(char::from(b).to_ascii_lowercase()).unwrap(),
+                b
+            );
+        }
+
+        #[test]
+        fn test_complement_twice_recovers_original(b in select(RNA::variants())) {
+            assert_eq!(b.complement().complement(), b);
+        }
+
+        // Converting to DNA and back is lossless and commutes with complement.
+        #[test]
+        fn test_dna_roundtrip(b in select(RNA::variants())) {
+            let dna = DNA::from(b);
+            assert_eq!(RNA::from(dna), b);
+            assert_eq!(dna.rank(), b.rank());
+            assert_eq!(DNA::from(b.complement()), dna.complement());
+        }
+
+        #[test]
+        fn test_union_is_reciprocal(
+            base1 in select(RNA::variants()),
+            base2 in select(RNA::variants()),
+        ) {
+            assert_eq!(base1.union(&base2), base2.union(&base1));
+        }
+
+        // A \ B == A intersect (B^complement^)
+        #[test]
+        fn test_intersection_gives_difference(
+            base1 in select(RNA::variants()),
+            base2 in select(RNA::variants()),
+        ) {
+            let compl: Option<RNA> = RNA::N.difference(&base2);
+            let diff: Option<RNA> = compl
+                .and_then(|c| base1.intersection(&c));
+
+            assert_eq!(base1.difference(&base2), diff);
+        }
+    } // End proptest!
+}
@@ -0,0 +1,216 @@
+//! A standard non-redundant RNA alphabet.
+use std::convert::TryFrom;
+
+use crate::alphabet::Alphabet;
+use crate::alphabet::{DNA4, RNA};
+use crate::complement::Complement;
+use crate::errors::{Position, SeqError, SeqErrorKind};
+use crate::matcher::Match;
+
+/// A Non-redundant four letter RNA alphabet.
+///
+/// The bit layout is the same as [`DNA4`], with `U` taking the place of `T`.
+///
+/// [`DNA4`]: enum.DNA4.html
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum RNA4 {
+    A = 0b00,
+    C = 0b01,
+    G = 0b10,
+    U = 0b11,
+}
+
+impl Alphabet for RNA4 {
+    /// The number of letters in this alphabet.
+    fn cardinality() -> usize {
+        4
+    }
+
+    fn rank(&self) -> usize {
+        *self as usize
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(r < Self::cardinality());
+        std::mem::transmute::<u8, Self>(r as u8)
+    }
+
+    /// Returns a Vec of all of the Enum variants.
+    fn variants() -> Vec<Self> {
+        vec![RNA4::A, RNA4::C, RNA4::G, RNA4::U]
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&u8> for RNA4 {
+        type Error = SeqError;
+        fn try_from(base: &u8) -> Result<Self, Self::Error> {
+            match base.to_ascii_uppercase() {
+                b'A' => Ok(RNA4::A),
+                b'C' => Ok(RNA4::C),
+                b'G' => Ok(RNA4::G),
+                b'U' => Ok(RNA4::U),
+                b => Err(SeqErrorKind::AlphabetReadError {
+                    base: b as char,
+                    position: Position(None),
+                }
+                .into()),
+            }
+        }
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&char> for RNA4 {
+        type Error = SeqError;
+        fn try_from(base: &char) -> Result<Self, Self::Error> {
+            crate::utils::char_to_byte(base).and_then(|b| Self::try_from(&b))
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&RNA4> for u8 {
+        fn from(base: &RNA4) -> Self {
+            match base {
+                RNA4::A => b'A',
+                RNA4::C => b'C',
+                RNA4::G => b'G',
+                RNA4::U => b'U',
+            }
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&RNA4> for char {
+        fn from(base: &RNA4) -> Self {
+            u8::from(base) as char
+        }
+    }
+}
+
+impl std::fmt::Display for RNA4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+impl Complement for RNA4 {
+    type Compl = RNA4;
+    fn complement(&self) -> Self::Compl {
+        let a = *self as u8;
+        let comp = a ^ 0b11;
+
+        unsafe { std::mem::transmute::<u8, Self::Compl>(comp) }
+    }
+}
+
+impl Match<RNA4> for RNA4 {
+    fn matches(&self, other: &RNA4) -> bool {
+        self == other
+    }
+}
+
+try_from_borrowed! {
+    impl TryFrom<&RNA> for RNA4 {
+        type Error = SeqError;
+        fn try_from(base: &RNA) -> Result<Self, Self::Error> {
+            let a = *base as u8;
+            // If RNA has more than 1 bit set it's redundant, so not representable.
+            if a.count_ones() > 1 {
+                Err(SeqErrorKind::RedundantAlphabetConversionError {
+                    base: char::from(base),
+                }
+                .into())
+            } else {
+                let b = a.trailing_zeros() as u8;
+                unsafe { Ok(std::mem::transmute::<u8, RNA4>(b)) }
+            }
+        }
+    }
+}
+
+// DNA4 and RNA4 share a bit layout, so conversions are just a relabelling.
+from_borrowed! {
+    impl From<&DNA4> for RNA4 {
+        fn from(base: &DNA4) -> Self {
+            unsafe { std::mem::transmute::<u8, RNA4>(*base as u8) }
+        }
+    }
+}
+
+from_borrowed! {
+    impl From<&RNA4> for DNA4 {
+        fn from(base: &RNA4) -> Self {
+            unsafe { std::mem::transmute::<u8, DNA4>(*base as u8) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::DNA;
+    use crate::complement::IntoReverseComplement;
+    use proptest::prelude::any;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_complement_vec() {
+        use super::RNA4::*;
+        let d = vec![A, U, G, C];
+        let c: Vec<RNA4> = d.iter().reverse_complement().collect();
+        assert_eq!(c, vec![G, C, A, U]);
+    }
+
+    #[test]
+    fn test_from_rna() {
+        assert_eq!(RNA4::try_from(RNA::A).unwrap(), RNA4::A);
+        assert_eq!(RNA4::try_from(RNA::C).unwrap(), RNA4::C);
+        assert_eq!(RNA4::try_from(RNA::G).unwrap(), RNA4::G);
+        assert_eq!(RNA4::try_from(RNA::U).unwrap(), RNA4::U);
+
+        assert!(RNA4::try_from(RNA::N).is_err());
+        assert!(RNA4::try_from(RNA::W).is_err());
+    }
+
+    #[test]
+    fn test_from_dna4() {
+        assert_eq!(RNA4::from(DNA4::T), RNA4::U);
+        assert_eq!(DNA4::from(RNA4::U), DNA4::T);
+        assert!(RNA4::try_from(b'T').is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_from_u8_doesnt_crash(c in any::<u8>()) {
+            let _dummy = RNA4::try_from(c);
+        }
+
+        #[test]
+        fn test_from_to_u8_recovers_original(b in select(RNA4::variants())) {
+            assert_eq!(RNA4::try_from(u8::from(b)).unwrap(), b);
+            assert_eq!(
+                RNA4::try_from(u8::from(b).to_ascii_lowercase()).unwrap(),
+                b
+            );
+        }
+
+        #[test]
+        fn test_from_to_char_recovers_original(b in select(RNA4::variants())) {
+            assert_eq!(RNA4::try_from(char::from(b)).unwrap(), b);
+        }
+
+        // The DNA and RNA alphabets should agree on every path between them.
+        #[test]
+        fn test_conversions_commute(b in select(RNA4::variants())) {
+            let dna4 = DNA4::from(b);
+            assert_eq!(RNA4::from(dna4), b);
+            assert_eq!(RNA::from(DNA::from(dna4)), RNA::from(b));
+            assert_eq!(DNA4::from(b.complement()), dna4.complement());
+        }
+    } // End proptest!
+}
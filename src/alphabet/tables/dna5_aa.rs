@@ -0,0 +1,3833 @@
+mod trans {
+    use crate::alphabet::Alphabet;
+    use crate::alphabet::AA;
+    use crate::alphabet::AA::*;
+    use crate::alphabet::DNA5;
+    use crate::codon::Codon;
+    use crate::stopped::Stopped;
+    use crate::stopped::Stopped::{Res, Stop, StopOr};
+    use crate::translate::NCBITransTable;
+    use crate::translate::NCBITransTable::*;
+    use crate::translate::TranslationTable;
+
+    impl TranslationTable<Codon<DNA5>, Stopped<AA>> for NCBITransTable {
+        fn get(&self, k: &Codon<DNA5>) -> Stopped<AA> {
+            let index = k.rank();
+            match self {
+                Standard => CODONS_STANDARD[index],
+                VertebrateMito => CODONS_VERTEBRATE_MITO[index],
+                YeastMito => CODONS_YEAST_MITO[index],
+                MoldProtozoanCoelenterateMito => CODONS_MOLD_PROTOZOAN_COELENTERATE_MITO[index],
+                InvertebrateMito => CODONS_INVERTEBRATE_MITO[index],
+                CiliateDasycladaceanHexamita => CODONS_CILIATE_DASYCLADACEAN_HEXAMITA[index],
+                EchinodermFlatwormMito => CODONS_ECHINODERM_FLATWORM_MITO[index],
+                Euplotid => CODONS_EUPLOTID[index],
+                BacterialArchaealPlastid => CODONS_BACTERIAL_ARCHAEAL_PLASTID[index],
+                AltYeast => CODONS_ALT_YEAST[index],
+                AscidianMito => CODONS_ASCIDIAN_MITO[index],
+                AltFlatwormMito => CODONS_ALT_FLATWORM_MITO[index],
+                BlepharismaMacronuclear => CODONS_BLEPHARISMA_MACRONUCLEAR[index],
+                ChlorophyceanMito => CODONS_CHLOROPHYCEAN_MITO[index],
+                TrematodeMito => CODONS_TREMATODE_MITO[index],
+                ScenedesmusMito => CODONS_SCENEDESMUS_MITO[index],
+                ThraustochytriumMito => CODONS_THRAUSTOCHYTRIUM_MITO[index],
+                PterobranchiaMito => CODONS_PTEROBRANCHIA_MITO[index],
+                SR1Gracilibacteria => CODONS_S_R1_GRACILIBACTERIA[index],
+                Pachysolen => CODONS_PACHYSOLEN[index],
+                Karyorelict => CODONS_KARYORELICT[index],
+                Condylostoma => CODONS_CONDYLOSTOMA[index],
+                Mesodinium => CODONS_MESODINIUM[index],
+                Peritrich => CODONS_PERITRICH[index],
+                Blastocrithidia => CODONS_BLASTOCRITHIDIA[index],
+                BalanophoraceaePlastid => CODONS_BALANOPHORACEAE_PLASTID[index],
+                CephalodiscidaeMito => CODONS_CEPHALODISCIDAE_MITO[index],
+            }
+        }
+    }
+
+    // TABLES!!
+    const CODONS_STANDARD: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Stop,
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_VERTEBRATE_MITO: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Stop,
+        Res(S),
+        Stop,
+        Res(S),
+        StopOr(X),
+        Res(M),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(W),
+        Res(C),
+        Res(W),
+        Res(C),
+        Res(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_YEAST_MITO: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(M),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(W),
+        Res(C),
+        Res(W),
+        Res(C),
+        Res(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_MOLD_PROTOZOAN_COELENTERATE_MITO: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(W),
+        Res(C),
+        Res(W),
+        Res(C),
+        Res(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_INVERTEBRATE_MITO: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(M),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(W),
+        Res(C),
+        Res(W),
+        Res(C),
+        Res(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_CILIATE_DASYCLADACEAN_HEXAMITA: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(Y),
+        Res(Q),
+        Res(Y),
+        Res(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Stop,
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_ECHINODERM_FLATWORM_MITO: [Stopped<AA>; 125] = [
+        Res(N),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(W),
+        Res(C),
+        Res(W),
+        Res(C),
+        Res(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_EUPLOTID: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(C),
+        Res(C),
+        Res(W),
+        Res(C),
+        Res(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_BACTERIAL_ARCHAEAL_PLASTID: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Stop,
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_ALT_YEAST: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(S),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Stop,
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_ASCIDIAN_MITO: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(G),
+        Res(S),
+        Res(G),
+        Res(S),
+        Res(X),
+        Res(M),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(W),
+        Res(C),
+        Res(W),
+        Res(C),
+        Res(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_ALT_FLATWORM_MITO: [Stopped<AA>; 125] = [
+        Res(N),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Y),
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(W),
+        Res(C),
+        Res(W),
+        Res(C),
+        Res(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_BLEPHARISMA_MACRONUCLEAR: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Res(Q),
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Stop,
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_CHLOROPHYCEAN_MITO: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Res(L),
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Stop,
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_TREMATODE_MITO: [Stopped<AA>; 125] = [
+        Res(N),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(M),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(W),
+        Res(C),
+        Res(W),
+        Res(C),
+        Res(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_SCENEDESMUS_MITO: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Res(L),
+        Res(Y),
+        StopOr(X),
+        Stop,
+        Res(S),
+        Res(S),
+        Res(S),
+        StopOr(X),
+        Stop,
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_THRAUSTOCHYTRIUM_MITO: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Stop,
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Stop,
+        Res(F),
+        Res(L),
+        Res(F),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_PTEROBRANCHIA_MITO: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(S),
+        Res(S),
+        Res(K),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(W),
+        Res(C),
+        Res(W),
+        Res(C),
+        Res(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_S_R1_GRACILIBACTERIA: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(G),
+        Res(C),
+        Res(W),
+        Res(C),
+        Res(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_PACHYSOLEN: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(A),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Stop,
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Stop,
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_KARYORELICT: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(Y),
+        Res(Q),
+        Res(Y),
+        Res(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        StopOr(W),
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_CONDYLOSTOMA: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(Q),
+        Res(Y),
+        StopOr(Q),
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        StopOr(W),
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_MESODINIUM: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Y),
+        Res(Y),
+        Res(Y),
+        Res(Y),
+        Res(Y),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Stop,
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_PERITRICH: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(Y),
+        Res(E),
+        Res(Y),
+        Res(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Stop,
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_BLASTOCRITHIDIA: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(E),
+        Res(Y),
+        StopOr(E),
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(W),
+        Res(C),
+        Res(W),
+        Res(C),
+        Res(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_BALANOPHORACEAE_PLASTID: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(R),
+        Res(S),
+        Res(R),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(W),
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Stop,
+        Res(C),
+        Res(W),
+        Res(C),
+        StopOr(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+    const CODONS_CEPHALODISCIDAE_MITO: [Stopped<AA>; 125] = [
+        Res(K),
+        Res(N),
+        Res(K),
+        Res(N),
+        Res(X),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(T),
+        Res(S),
+        Res(S),
+        Res(K),
+        Res(S),
+        Res(X),
+        Res(I),
+        Res(I),
+        Res(M),
+        Res(I),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Q),
+        Res(H),
+        Res(Q),
+        Res(H),
+        Res(X),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(P),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(R),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(L),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(E),
+        Res(D),
+        Res(E),
+        Res(D),
+        Res(X),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(A),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(G),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(V),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(Y),
+        Res(Y),
+        Stop,
+        Res(Y),
+        StopOr(X),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(S),
+        Res(W),
+        Res(C),
+        Res(W),
+        Res(C),
+        Res(X),
+        Res(L),
+        Res(F),
+        Res(L),
+        Res(F),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        Res(X),
+        StopOr(X),
+        Res(X),
+        StopOr(X),
+    ];
+}
+
+mod tags {
+    use crate::alphabet::Alphabet;
+    use crate::alphabet::CodonTag;
+    use crate::alphabet::CodonTag::*;
+    use crate::alphabet::DNA5;
+    use crate::codon::Codon;
+    use crate::translate::CodonTagTable;
+    use crate::translate::NCBITransTable;
+    use crate::translate::NCBITransTable::*;
+
+    impl CodonTagTable<Codon<DNA5>, CodonTag> for NCBITransTable {
+        fn get_tag(&self, k: &Codon<DNA5>) -> CodonTag {
+            let index = k.rank();
+            match self {
+                Standard => TAGS_STANDARD[index],
+                VertebrateMito => TAGS_VERTEBRATE_MITO[index],
+                YeastMito => TAGS_YEAST_MITO[index],
+                MoldProtozoanCoelenterateMito => TAGS_MOLD_PROTOZOAN_COELENTERATE_MITO[index],
+                InvertebrateMito => TAGS_INVERTEBRATE_MITO[index],
+                CiliateDasycladaceanHexamita => TAGS_CILIATE_DASYCLADACEAN_HEXAMITA[index],
+                EchinodermFlatwormMito => TAGS_ECHINODERM_FLATWORM_MITO[index],
+                Euplotid => TAGS_EUPLOTID[index],
+                BacterialArchaealPlastid => TAGS_BACTERIAL_ARCHAEAL_PLASTID[index],
+                AltYeast => TAGS_ALT_YEAST[index],
+                AscidianMito => TAGS_ASCIDIAN_MITO[index],
+                AltFlatwormMito => TAGS_ALT_FLATWORM_MITO[index],
+                BlepharismaMacronuclear => TAGS_BLEPHARISMA_MACRONUCLEAR[index],
+                ChlorophyceanMito => TAGS_CHLOROPHYCEAN_MITO[index],
+                TrematodeMito => TAGS_TREMATODE_MITO[index],
+                ScenedesmusMito => TAGS_SCENEDESMUS_MITO[index],
+                ThraustochytriumMito => TAGS_THRAUSTOCHYTRIUM_MITO[index],
+                PterobranchiaMito => TAGS_PTEROBRANCHIA_MITO[index],
+                SR1Gracilibacteria => TAGS_S_R1_GRACILIBACTERIA[index],
+                Pachysolen => TAGS_PACHYSOLEN[index],
+                Karyorelict => TAGS_KARYORELICT[index],
+                Condylostoma => TAGS_CONDYLOSTOMA[index],
+                Mesodinium => TAGS_MESODINIUM[index],
+                Peritrich => TAGS_PERITRICH[index],
+                Blastocrithidia => TAGS_BLASTOCRITHIDIA[index],
+                BalanophoraceaePlastid => TAGS_BALANOPHORACEAE_PLASTID[index],
+                CephalodiscidaeMito => TAGS_CEPHALODISCIDAE_MITO[index],
+            }
+        }
+    }
+
+    const TAGS_STANDARD: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, StartRes, Res, Res, StartRes, Res,
+        StartRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Stop, Res, Stop, Res, StopRes, Res, Res, Res,
+        Res, Res, Stop, Res, Res, Res, StopRes, Res, Res, Start, Res, StartRes, StopRes, Res, Any,
+        Res, Any, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, Res,
+        Res, StopRes, Res, Res, StartRes, Res, StartRes, StopRes, Res, Any, Res, Any,
+    ];
+    const TAGS_VERTEBRATE_MITO: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Stop, Res, Stop, Res, StopRes, Start,
+        Start, Start, Start, Start, Any, StartRes, Any, StartRes, Any, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Start, Res, StartRes, Res, Res, StartRes, Res, StartRes, Stop, Res, Stop, Res, StopRes, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, StopRes, Res, StopRes,
+        Res, StopRes, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res,
+        StopRes, Res, StopRes, StartRes, StartRes, StartRes, StartRes, StartRes, Any, StartRes, Any,
+        StartRes, Any,
+    ];
+    const TAGS_YEAST_MITO: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res,
+        Start, Res, StartRes, StartRes, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Start, Res, StartRes, Res, Res, StartRes, Res, StartRes, Stop, Res, Stop, Res, StopRes, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, StopRes, Res, StopRes,
+        Res, StopRes, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, StartRes, Res, StartRes, Res, StartRes, Any, Res, Any, Res, Any,
+    ];
+    const TAGS_MOLD_PROTOZOAN_COELENTERATE_MITO: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Start,
+        Start, Start, Start, StartRes, StartRes, StartRes, StartRes, StartRes, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, StartRes, Res,
+        Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Start, Res, StartRes, Res, Res, StartRes, Res, StartRes, Stop, Res,
+        Stop, Res, StopRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, Start,
+        Res, StartRes, Any, Res, Any, Res, Any, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, StartRes, StartRes, Start, StartRes, StartRes, Any,
+        StartRes, Any, StartRes, Any,
+    ];
+    const TAGS_INVERTEBRATE_MITO: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Start,
+        Start, Start, Start, StartRes, StartRes, StartRes, StartRes, StartRes, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Start, Res, StartRes, Res, Res, StartRes, Res, StartRes, Stop, Res, Stop, Res,
+        StopRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, StartRes,
+        StopRes, Res, Any, Res, Any, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, StartRes, StartRes, StartRes, StartRes, StartRes, Any, StartRes,
+        Any, StartRes, Any,
+    ];
+    const TAGS_CILIATE_DASYCLADACEAN_HEXAMITA: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Stop, Res,
+        Res, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes, Res, Res, StartRes, Res,
+        StartRes, StopRes, Res, StartRes, Res, Any,
+    ];
+    const TAGS_ECHINODERM_FLATWORM_MITO: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res,
+        StartRes, Res, Res, StartRes, Res, StartRes, Stop, Res, Stop, Res, StopRes, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, StopRes, Res, StopRes, Res,
+        StopRes, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, StartRes, Res, StartRes, StopRes, Res, Any, Res, Any,
+    ];
+    const TAGS_EUPLOTID: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Stop, Res, Stop, Res, StopRes, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, StopRes, Res, StopRes, Res, StopRes, StopRes,
+        Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        StartRes, Res, StartRes, StopRes, Res, Any, Res, Any,
+    ];
+    const TAGS_BACTERIAL_ARCHAEAL_PLASTID: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Start,
+        Start, Start, Start, StartRes, StartRes, StartRes, StartRes, StartRes, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, StartRes, Res,
+        Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Start, Res, StartRes, Res, Res, StartRes, Res, StartRes, Stop, Res,
+        Stop, Res, StopRes, Res, Res, Res, Res, Res, Stop, Res, Res, Res, StopRes, Res, Res, Start,
+        Res, StartRes, StopRes, Res, Any, Res, Any, StopRes, Res, StopRes, Res, StopRes, Res, Res,
+        Res, Res, Res, StopRes, Res, Res, Res, StopRes, StartRes, StartRes, Start, StartRes,
+        StartRes, Any, StartRes, Any, StartRes, Any,
+    ];
+    const TAGS_ALT_YEAST: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, StartRes, Res, Res, StartRes, Res,
+        StartRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Stop, Res, Stop, Res, StopRes, Res, Res, Res,
+        Res, Res, Stop, Res, Res, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, StopRes, Res,
+        StopRes, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, Res,
+        Res, StopRes, Res, Res, StartRes, Res, StartRes, StopRes, Res, Any, Res, Any,
+    ];
+    const TAGS_ASCIDIAN_MITO: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res,
+        Start, Res, StartRes, StartRes, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Start, Res, StartRes, Res, Res, StartRes, Res, StartRes, Stop, Res, Stop, Res, StopRes, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, StartRes, StopRes, Res,
+        Any, Res, Any, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, StartRes, Res, StartRes, Res, StartRes, Any, Res, Any, Res, Any,
+    ];
+    const TAGS_ALT_FLATWORM_MITO: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Stop, Res, StopRes, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, StopRes, Res, StopRes, Res, Res,
+        StopRes, Res, StopRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, StartRes,
+        Res, StartRes, Res, Res, Any, Res, Any,
+    ];
+    const TAGS_BLEPHARISMA_MACRONUCLEAR: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Stop, Res, Res, Res, StopRes, Res, Res, Res, Res, Res, Stop,
+        Res, Res, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes, StopRes,
+        Res, Res, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes, Res, Res,
+        StartRes, Res, StartRes, StopRes, Res, StartRes, Res, Any,
+    ];
+    const TAGS_CHLOROPHYCEAN_MITO: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Stop, Res, Res, Res, StopRes, Res, Res, Res, Res, Res, Stop,
+        Res, Res, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes, StopRes,
+        Res, Res, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes, Res, Res,
+        StartRes, Res, StartRes, StopRes, Res, StartRes, Res, Any,
+    ];
+    const TAGS_TREMATODE_MITO: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res,
+        StartRes, Res, Res, StartRes, Res, StartRes, Stop, Res, Stop, Res, StopRes, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, StopRes, Res, StopRes, Res,
+        StopRes, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, StartRes, Res, StartRes, StopRes, Res, Any, Res, Any,
+    ];
+    const TAGS_SCENEDESMUS_MITO: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Stop, Res, Res, Res, StopRes, Stop, Res, Res, Res, StopRes,
+        Stop, Res, Res, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes,
+        StopRes, Res, Res, Res, StopRes, StopRes, Res, Res, Res, StopRes, StopRes, Res, Res, Res,
+        StopRes, Res, Res, StartRes, Res, StartRes, StopRes, Res, StartRes, Res, Any,
+    ];
+    const TAGS_THRAUSTOCHYTRIUM_MITO: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Start, StartRes, Res, Res, StartRes, StartRes, StartRes, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Stop, Res, Stop, Res, StopRes, Res, Res,
+        Res, Res, Res, Stop, Res, Res, Res, StopRes, Stop, Res, Res, Res, StopRes, StopRes, Res,
+        StopRes, Res, StopRes, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res,
+        StopRes, Res, Res, Res, StopRes, StopRes, Res, StartRes, StartRes, Any, StopRes, Res, Any,
+        StartRes, Any,
+    ];
+    const TAGS_PTEROBRANCHIA_MITO: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, StartRes, Res, Res, StartRes, Res,
+        StartRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Start, Res, StartRes, Res, Res, StartRes, Res, StartRes, Stop, Res, Stop, Res, StopRes,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, StartRes, StopRes,
+        Res, Any, Res, Any, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Start, Res, StartRes, StopRes, Res, Any, Res, Any,
+    ];
+    const TAGS_S_R1_GRACILIBACTERIA: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res,
+        StartRes, Res, Res, StartRes, Res, StartRes, Stop, Res, Stop, Res, StopRes, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, StartRes, StopRes, Res, Any, Res,
+        Any, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, StartRes, Res, StartRes, StopRes, Res, Any, Res, Any,
+    ];
+    const TAGS_PACHYSOLEN: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, StartRes, Res, Res, StartRes, Res,
+        StartRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Stop, Res, Stop, Res, StopRes, Res, Res, Res,
+        Res, Res, Stop, Res, Res, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, StopRes, Res,
+        StopRes, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, Res,
+        Res, StopRes, Res, Res, StartRes, Res, StartRes, StopRes, Res, Any, Res, Any,
+    ];
+    const TAGS_KARYORELICT: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, StopRes,
+        Res, Res, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes, Res, Res, StartRes,
+        Res, StartRes, StopRes, Res, StartRes, Res, Any,
+    ];
+    const TAGS_CONDYLOSTOMA: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res,
+        StopRes, Res, Res, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, StopRes, Res,
+        StopRes, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, Res,
+        Res, StopRes, Res, Res, StartRes, Res, StartRes, StopRes, Res, Any, Res, Any,
+    ];
+    const TAGS_MESODINIUM: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Stop, Res,
+        Res, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes, Res, Res, StartRes, Res,
+        StartRes, StopRes, Res, StartRes, Res, Any,
+    ];
+    const TAGS_PERITRICH: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Stop, Res,
+        Res, Res, StopRes, Res, Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes, Res, Res, StartRes, Res,
+        StartRes, StopRes, Res, StartRes, Res, Any,
+    ];
+    const TAGS_BLASTOCRITHIDIA: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, StopRes, Res, StopRes, Res, StopRes,
+        StopRes, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, StartRes, Res, StartRes, StopRes, Res, Any, Res, Any,
+    ];
+    const TAGS_BALANOPHORACEAE_PLASTID: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Start,
+        Start, Start, Start, StartRes, StartRes, StartRes, StartRes, StartRes, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, StartRes, Res,
+        Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Start, Res, StartRes, Res, Res, StartRes, Res, StartRes, StopRes,
+        Res, Stop, Res, StopRes, Res, Res, Res, Res, Res, Stop, Res, Res, Res, StopRes, Res, Res,
+        Start, Res, StartRes, StopRes, Res, Any, Res, Any, StopRes, Res, StopRes, Res, StopRes, Res,
+        Res, Res, Res, Res, StopRes, Res, Res, Res, StopRes, StartRes, StartRes, Start, StartRes,
+        StartRes, Any, StartRes, Any, StartRes, Any,
+    ];
+    const TAGS_CEPHALODISCIDAE_MITO: [CodonTag; 125] = [
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start,
+        Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, StartRes, Res, Res, StartRes, Res,
+        StartRes, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Start, Res, StartRes, Res, Res, StartRes, Res, StartRes, Res, Res, Stop, Res, StopRes,
+        Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Res, Start, Res, StartRes, Res, Res,
+        Any, Res, Any, Res, Res, StopRes, Res, StopRes, Res, Res, Res, Res, Res, Res, Res, Res, Res,
+        Res, Res, Res, Start, Res, StartRes, Res, Res, Any, Res, Any,
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alphabet::Alphabet;
+    use crate::alphabet::{AA, DNA, DNA5};
+    use crate::codon::Codon;
+    use crate::stopped::Stopped;
+    use crate::translate::CodonTagTable;
+    use crate::translate::NCBITransTable;
+    use crate::translate::TranslationTable;
+
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    proptest! {
+        // The DNA5 tables are a subset of the DNA tables.
+        #[test]
+        fn test_tables_match_dna(
+            b1 in select(DNA5::variants()),
+            b2 in select(DNA5::variants()),
+            b3 in select(DNA5::variants()),
+            table in select(NCBITransTable::variants()),
+        ) {
+            let codon = Codon(b1, b2, b3);
+            let dna = Codon(DNA::from(b1), DNA::from(b2), DNA::from(b3));
+
+            let aa: Stopped<AA> = table.get(&codon);
+            assert_eq!(aa, table.get(&dna));
+            assert_eq!(table.get_tag(&codon), table.get_tag(&dna));
+        }
+    }
+}
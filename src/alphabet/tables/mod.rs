@@ -1,2 +1,4 @@
 mod dna4_aa;
+mod dna5_aa;
 mod dna_aa;
+mod rna_aa;
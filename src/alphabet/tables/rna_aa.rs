@@ -0,0 +1,102 @@
+//! RNA codons share the layout of the DNA codon tables, so lookups are
+//! delegated to the DNA implementations.
+
+mod trans {
+    use crate::alphabet::{AA, DNA, DNA4, RNA, RNA4};
+    use crate::codon::Codon;
+    use crate::stopped::Stopped;
+    use crate::translate::NCBITransTable;
+    use crate::translate::TranslationTable;
+
+    impl TranslationTable<Codon<RNA>, Stopped<AA>> for NCBITransTable {
+        fn get(&self, k: &Codon<RNA>) -> Stopped<AA> {
+            let codon = k.as_ref().map_each(DNA::from);
+            self.get(&codon)
+        }
+    }
+
+    impl TranslationTable<Codon<RNA4>, Stopped<AA>> for NCBITransTable {
+        fn get(&self, k: &Codon<RNA4>) -> Stopped<AA> {
+            let codon = k.as_ref().map_each(DNA4::from);
+            self.get(&codon)
+        }
+    }
+}
+
+mod tags {
+    use crate::alphabet::CodonTag;
+    use crate::alphabet::{DNA, DNA4, RNA, RNA4};
+    use crate::codon::Codon;
+    use crate::translate::CodonTagTable;
+    use crate::translate::NCBITransTable;
+
+    impl CodonTagTable<Codon<RNA>, CodonTag> for NCBITransTable {
+        fn get_tag(&self, k: &Codon<RNA>) -> CodonTag {
+            let codon = k.as_ref().map_each(DNA::from);
+            self.get_tag(&codon)
+        }
+    }
+
+    impl CodonTagTable<Codon<RNA4>, CodonTag> for NCBITransTable {
+        fn get_tag(&self, k: &Codon<RNA4>) -> CodonTag {
+            let codon = k.as_ref().map_each(DNA4::from);
+            self.get_tag(&codon)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alphabet::{Alphabet, AA, DNA, DNA4, RNA, RNA4};
+    use crate::codon::Codon;
+    use crate::stopped::Stopped;
+    use crate::translate::CodonTagTable;
+    use crate::translate::NCBITransTable;
+    use crate::translate::TranslationTable;
+
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_translate() {
+        let table = NCBITransTable::Standard;
+        let aug = Codon(RNA4::A, RNA4::U, RNA4::G);
+        let uga = Codon(RNA::U, RNA::G, RNA::A);
+        assert_eq!(table.get(&aug), Stopped::Res(AA::M));
+        assert_eq!(table.get(&uga), Stopped::Stop);
+        assert_eq!(
+            NCBITransTable::VertebrateMito.get(&uga),
+            Stopped::Res(AA::W)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_rna_matches_dna(
+            b1 in select(RNA::variants()),
+            b2 in select(RNA::variants()),
+            b3 in select(RNA::variants()),
+            table in select(NCBITransTable::variants()),
+        ) {
+            let rna = Codon(b1, b2, b3);
+            let dna = Codon(DNA::from(b1), DNA::from(b2), DNA::from(b3));
+            let aa: Stopped<AA> = table.get(&rna);
+            assert_eq!(aa, table.get(&dna));
+            assert_eq!(table.get_tag(&rna), table.get_tag(&dna));
+        }
+
+        #[test]
+        fn test_rna4_matches_dna4(
+            b1 in select(RNA4::variants()),
+            b2 in select(RNA4::variants()),
+            b3 in select(RNA4::variants()),
+            table in select(NCBITransTable::variants()),
+        ) {
+            let rna = Codon(b1, b2, b3);
+            let dna = Codon(DNA4::from(b1), DNA4::from(b2), DNA4::from(b3));
+            let aa: Stopped<AA> = table.get(&rna);
+            assert_eq!(aa, table.get(&dna));
+            assert_eq!(table.get_tag(&rna), table.get_tag(&dna));
+        }
+    }
+}
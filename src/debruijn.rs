@@ -0,0 +1,763 @@
+//! De Bruijn graphs built from kmers.
+//!
+//! The graph is node-centric: its nodes are the kmers in a set of sequences,
+//! and an edge joins two kmers if the last `k - 1` bases of the first are
+//! the first `k - 1` bases of the second. Graphs can either be built from
+//! forward kmers, or from canonical kmers in which case each node represents
+//! a kmer and its reverse complement.
+//!
+//! Non-branching paths can be compacted into unitigs, forming a
+//! [`UnitigGraph`] that can be cleaned of tips and bubbles and written out
+//! as [GFA].
+//!
+//! # Examples:
+//!
+//! ```
+//! use seqrs::alphabet::DNA4;
+//! use seqrs::alphabet::DNA4::*;
+//! use seqrs::debruijn::{DeBruijnGraph, GraphMode};
+//! use typenum::U5;
+//!
+//! let mut graph: DeBruijnGraph<DNA4, U5> = DeBruijnGraph::new(GraphMode::Canonical);
+//! graph.add_sequence(&[C, C, G, T, A, A, T, G, C, C]);
+//! assert_eq!(graph.len(), 6);
+//!
+//! // The reverse complement gives the same graph.
+//! let mut rc: DeBruijnGraph<DNA4, U5> = DeBruijnGraph::new(GraphMode::Canonical);
+//! rc.add_sequence(&[G, G, C, A, T, T, A, C, G, G]);
+//! assert_eq!(graph.edges(), rc.edges());
+//!
+//! let unitigs = graph.unitigs();
+//! assert_eq!(unitigs.unitigs.len(), 1);
+//! assert_eq!(unitigs.unitigs[0].len(), 10);
+//! ```
+//!
+//! [`UnitigGraph`]: struct.UnitigGraph.html
+//! [GFA]: https://github.com/GFA-spec/GFA-spec
+
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::io::Write;
+use typenum::Unsigned;
+
+use crate::alphabet::Alphabet;
+use crate::complement::Complement;
+use crate::kmer::{IntoKmers, Kmer, RollingKmer, SimpleKmer, Strand};
+
+/// Whether a graph distinguishes a kmer from its reverse complement.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum GraphMode {
+    /// Kmers are only read from the forward strand.
+    Forward,
+    /// Each node represents a kmer and its reverse complement.
+    Canonical,
+}
+
+/// A de Bruijn graph of kmers and their counts.
+#[derive(Debug, Clone)]
+pub struct DeBruijnGraph<A, K> {
+    mode: GraphMode,
+    counts: HashMap<SimpleKmer<A, K>, u32>,
+}
+
+impl<A, K> DeBruijnGraph<A, K>
+where
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+{
+    /// Create an empty graph.
+    pub fn new(mode: GraphMode) -> Self {
+        DeBruijnGraph {
+            mode: mode,
+            counts: HashMap::new(),
+        }
+    }
+
+    /// Whether the graph uses forward or canonical kmers.
+    pub fn mode(&self) -> GraphMode {
+        self.mode
+    }
+
+    /// The number of nodes in the graph.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns true if the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The kmer stored for a node, i.e. the canonical kmer in canonical
+    /// graphs.
+    #[inline]
+    fn key(&self, kmer: SimpleKmer<A, K>) -> SimpleKmer<A, K> {
+        match self.mode {
+            GraphMode::Forward => kmer,
+            GraphMode::Canonical => kmer.canonical(),
+        }
+    }
+
+    /// Add a kmer to the graph, incrementing its count.
+    pub fn add_kmer(&mut self, kmer: SimpleKmer<A, K>) {
+        let count = self.counts.entry(self.key(kmer)).or_insert(0);
+        *count = count.saturating_add(1);
+    }
+
+    /// Add all kmers in a sequence to the graph.
+    pub fn add_sequence<I>(&mut self, seq: I)
+    where
+        I: IntoIterator,
+        I::Item: Borrow<A>,
+    {
+        for kmer in IntoKmers::<A>::kmers::<K>(seq.into_iter()) {
+            self.add_kmer(kmer);
+        }
+    }
+
+    /// Remove nodes seen fewer than `min_count` times, e.g. kmers containing
+    /// sequencing errors.
+    pub fn retain_solid(&mut self, min_count: u32) {
+        self.counts.retain(|_, &mut c| c >= min_count);
+    }
+
+    /// The number of times a kmer (or in canonical graphs, its reverse
+    /// complement) was added, or `None` if it isn't in the graph.
+    pub fn count(&self, kmer: &SimpleKmer<A, K>) -> Option<u32> {
+        self.counts.get(&self.key(*kmer)).cloned()
+    }
+
+    /// Returns true if the graph contains a kmer.
+    pub fn contains(&self, kmer: &SimpleKmer<A, K>) -> bool {
+        self.counts.contains_key(&self.key(*kmer))
+    }
+
+    /// Iterate over the nodes in the graph and their counts.
+    /// In canonical graphs the canonical kmers are yielded.
+    pub fn nodes<'a>(&'a self) -> impl Iterator<Item = (SimpleKmer<A, K>, u32)> + 'a {
+        self.counts.iter().map(|(&k, &c)| (k, c))
+    }
+
+    /// The kmers that follow a kmer in the graph.
+    ///
+    /// In canonical graphs the kmers are oriented to overlap the given kmer.
+    pub fn successors(&self, kmer: &SimpleKmer<A, K>) -> Vec<SimpleKmer<A, K>> {
+        (0..A::cardinality())
+            .map(|r| {
+                let mut next = *kmer;
+                next.push_back(&unsafe { A::from_rank_unsafe(r) });
+                next
+            })
+            .filter(|next| self.contains(next))
+            .collect()
+    }
+
+    /// The kmers that precede a kmer in the graph.
+    ///
+    /// In canonical graphs the kmers are oriented to overlap the given kmer.
+    pub fn predecessors(&self, kmer: &SimpleKmer<A, K>) -> Vec<SimpleKmer<A, K>> {
+        (0..A::cardinality())
+            .map(|r| {
+                let mut prev = *kmer;
+                prev.push_front(&unsafe { A::from_rank_unsafe(r) });
+                prev
+            })
+            .filter(|prev| self.contains(prev))
+            .collect()
+    }
+
+    /// All edges in the graph.
+    ///
+    /// In canonical graphs the edges `x -> y` and `rc(y) -> rc(x)` are the
+    /// same, and only one of them is reported.
+    pub fn edges(&self) -> Vec<(SimpleKmer<A, K>, SimpleKmer<A, K>)> {
+        let mut edges = HashSet::new();
+
+        for &node in self.counts.keys() {
+            for from in self.orientations(node) {
+                for to in self.successors(&from) {
+                    edges.insert(self.normalise_edge(from, to));
+                }
+            }
+        }
+
+        let mut edges: Vec<_> = edges.into_iter().collect();
+        edges.sort();
+        edges
+    }
+
+    /// The orientations in which a node can be traversed.
+    fn orientations(&self, node: SimpleKmer<A, K>) -> Vec<SimpleKmer<A, K>> {
+        match self.mode {
+            GraphMode::Forward => vec![node],
+            GraphMode::Canonical => {
+                let rc = node.reverse_complement();
+                if rc == node {
+                    vec![node]
+                } else {
+                    vec![node, rc]
+                }
+            }
+        }
+    }
+
+    fn normalise_edge(
+        &self,
+        from: SimpleKmer<A, K>,
+        to: SimpleKmer<A, K>,
+    ) -> (SimpleKmer<A, K>, SimpleKmer<A, K>) {
+        match self.mode {
+            GraphMode::Forward => (from, to),
+            GraphMode::Canonical => {
+                let flipped = (to.reverse_complement(), from.reverse_complement());
+                (from, to).min(flipped)
+            }
+        }
+    }
+
+    /// The next kmer in a non-branching path, if there is exactly one.
+    fn unique_successor(&self, kmer: &SimpleKmer<A, K>) -> Option<SimpleKmer<A, K>> {
+        let next = self.successors(kmer);
+        if next.len() == 1 && self.predecessors(&next[0]).len() == 1 {
+            Some(next[0])
+        } else {
+            None
+        }
+    }
+
+    /// The previous kmer in a non-branching path, if there is exactly one.
+    fn unique_predecessor(&self, kmer: &SimpleKmer<A, K>) -> Option<SimpleKmer<A, K>> {
+        let prev = self.predecessors(kmer);
+        if prev.len() == 1 && self.successors(&prev[0]).len() == 1 {
+            Some(prev[0])
+        } else {
+            None
+        }
+    }
+
+    /// Compact the maximal non-branching paths of the graph into unitigs.
+    ///
+    /// Unitigs are ordered by their first kmer, so compaction is
+    /// deterministic.
+    pub fn unitigs(&self) -> UnitigGraph<A, K> {
+        let mut nodes: Vec<SimpleKmer<A, K>> = self.counts.keys().cloned().collect();
+        nodes.sort();
+
+        let mut visited: HashSet<SimpleKmer<A, K>> = HashSet::with_capacity(nodes.len());
+        let mut unitigs = Vec::new();
+
+        for start in nodes {
+            if visited.contains(&start) {
+                continue;
+            }
+            visited.insert(start);
+
+            let mut forward = Vec::new();
+            let mut kmer = start;
+            while let Some(next) = self.unique_successor(&kmer) {
+                if !visited.insert(self.key(next)) {
+                    break;
+                }
+                forward.push(next);
+                kmer = next;
+            }
+
+            let mut backward = Vec::new();
+            let mut kmer = start;
+            while let Some(prev) = self.unique_predecessor(&kmer) {
+                if !visited.insert(self.key(prev)) {
+                    break;
+                }
+                backward.push(prev);
+                kmer = prev;
+            }
+
+            backward.reverse();
+            backward.push(start);
+            backward.extend(forward);
+
+            let total: u64 = backward
+                .iter()
+                .map(|k| u64::from(self.count(k).unwrap_or(0)))
+                .sum();
+
+            unitigs.push(Unitig {
+                coverage: total as f64 / backward.len() as f64,
+                kmer_count: total,
+                kmers: backward,
+            });
+        }
+
+        let links = self.link_unitigs(&unitigs);
+        UnitigGraph {
+            mode: self.mode,
+            unitigs: unitigs,
+            links: links,
+        }
+    }
+
+    /// Find the edges joining the ends of unitigs.
+    fn link_unitigs(&self, unitigs: &[Unitig<A, K>]) -> Vec<Link> {
+        let strands = match self.mode {
+            GraphMode::Forward => vec![Strand::Forward],
+            GraphMode::Canonical => vec![Strand::Forward, Strand::Reverse],
+        };
+
+        // Map the first kmer of each oriented unitig to the unitig.
+        let mut starts: HashMap<SimpleKmer<A, K>, Vec<(usize, Strand)>> = HashMap::new();
+        for (i, unitig) in unitigs.iter().enumerate() {
+            for &strand in &strands {
+                starts
+                    .entry(unitig.first(strand))
+                    .or_insert_with(Vec::new)
+                    .push((i, strand));
+            }
+        }
+
+        let mut links = HashSet::new();
+        for (i, unitig) in unitigs.iter().enumerate() {
+            for &strand in &strands {
+                for next in self.successors(&unitig.last(strand)) {
+                    for &(j, to_strand) in starts.get(&next).into_iter().flatten() {
+                        let link = Link {
+                            from: i,
+                            from_strand: strand,
+                            to: j,
+                            to_strand: to_strand,
+                        };
+                        links.insert(match self.mode {
+                            GraphMode::Forward => link,
+                            GraphMode::Canonical => link.min(link.flip()),
+                        });
+                    }
+                }
+            }
+        }
+
+        let mut links: Vec<Link> = links.into_iter().collect();
+        links.sort();
+        links
+    }
+}
+
+/// A maximal non-branching path through a de Bruijn graph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unitig<A, K> {
+    /// The kmers along the path, each overlapping the next by `k - 1` bases.
+    pub kmers: Vec<SimpleKmer<A, K>>,
+    /// The mean count of the kmers in the unitig.
+    pub coverage: f64,
+    /// The sum of the counts of the kmers in the unitig.
+    pub kmer_count: u64,
+}
+
+impl<A, K> Unitig<A, K>
+where
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+{
+    /// The number of bases in the unitig.
+    pub fn len(&self) -> usize {
+        self.kmers.len() + K::to_usize() - 1
+    }
+
+    /// Returns true if the unitig has no kmers.
+    pub fn is_empty(&self) -> bool {
+        self.kmers.is_empty()
+    }
+
+    /// The sequence spelled by the unitig.
+    pub fn sequence(&self) -> Vec<A> {
+        let mut seq = self.kmers[0].bases();
+        seq.extend(
+            self.kmers[1..]
+                .iter()
+                .filter_map(|k| k.get(K::to_usize() - 1)),
+        );
+        seq
+    }
+
+    /// The first kmer when reading the unitig on a strand.
+    pub fn first(&self, strand: Strand) -> SimpleKmer<A, K> {
+        match strand {
+            Strand::Forward => self.kmers[0],
+            Strand::Reverse => self.kmers[self.kmers.len() - 1].reverse_complement(),
+        }
+    }
+
+    /// The last kmer when reading the unitig on a strand.
+    pub fn last(&self, strand: Strand) -> SimpleKmer<A, K> {
+        match strand {
+            Strand::Forward => self.kmers[self.kmers.len() - 1],
+            Strand::Reverse => self.kmers[0].reverse_complement(),
+        }
+    }
+}
+
+/// An edge from the end of one oriented unitig to the start of another.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Link {
+    pub from: usize,
+    pub from_strand: Strand,
+    pub to: usize,
+    pub to_strand: Strand,
+}
+
+impl Link {
+    /// The same link traversed on the opposite strand.
+    pub fn flip(&self) -> Self {
+        Link {
+            from: self.to,
+            from_strand: flip(self.to_strand),
+            to: self.from,
+            to_strand: flip(self.from_strand),
+        }
+    }
+}
+
+#[inline]
+fn flip(strand: Strand) -> Strand {
+    match strand {
+        Strand::Forward => Strand::Reverse,
+        Strand::Reverse => Strand::Forward,
+    }
+}
+
+/// Alternative paths between the same pair of oriented unitigs, typically
+/// caused by sequencing errors or variants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bubble {
+    pub source: (usize, Strand),
+    pub sink: (usize, Strand),
+    /// The oriented unitigs making up each branch of the bubble.
+    pub branches: Vec<(usize, Strand)>,
+}
+
+/// A compacted de Bruijn graph, where each node is a unitig.
+#[derive(Debug, Clone)]
+pub struct UnitigGraph<A, K> {
+    mode: GraphMode,
+    pub unitigs: Vec<Unitig<A, K>>,
+    pub links: Vec<Link>,
+}
+
+impl<A, K> UnitigGraph<A, K>
+where
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+{
+    /// The oriented unitigs following an oriented unitig.
+    pub fn successors(&self, unitig: usize, strand: Strand) -> Vec<(usize, Strand)> {
+        let mut next = Vec::new();
+        for link in &self.links {
+            if link.from == unitig && link.from_strand == strand {
+                next.push((link.to, link.to_strand));
+            }
+
+            // Canonical links are also traversed in reverse.
+            let flipped = link.flip();
+            if self.mode == GraphMode::Canonical
+                && flipped != *link
+                && flipped.from == unitig
+                && flipped.from_strand == strand
+            {
+                next.push((flipped.to, flipped.to_strand));
+            }
+        }
+        next
+    }
+
+    /// The oriented unitigs preceding an oriented unitig.
+    pub fn predecessors(&self, unitig: usize, strand: Strand) -> Vec<(usize, Strand)> {
+        match self.mode {
+            GraphMode::Forward => self
+                .links
+                .iter()
+                .filter(|l| l.to == unitig)
+                .map(|l| (l.from, l.from_strand))
+                .collect(),
+            GraphMode::Canonical => self
+                .successors(unitig, flip(strand))
+                .into_iter()
+                .map(|(u, s)| (u, flip(s)))
+                .collect(),
+        }
+    }
+
+    /// Unitigs with at most `max_kmers` kmers that are a dead end on one side
+    /// and connected on the other.
+    pub fn tips(&self, max_kmers: usize) -> Vec<usize> {
+        (0..self.unitigs.len())
+            .filter(|&i| self.unitigs[i].kmers.len() <= max_kmers)
+            .filter(|&i| {
+                let n_out = self.successors(i, Strand::Forward).len();
+                let n_in = self.predecessors(i, Strand::Forward).len();
+                (n_out == 0) != (n_in == 0)
+            })
+            .collect()
+    }
+
+    /// Find simple bubbles, where two or more unitigs of at most `max_kmers`
+    /// kmers each leave the same oriented unitig and rejoin at another.
+    pub fn bubbles(&self, max_kmers: usize) -> Vec<Bubble> {
+        let strands = match self.mode {
+            GraphMode::Forward => vec![Strand::Forward],
+            GraphMode::Canonical => vec![Strand::Forward, Strand::Reverse],
+        };
+
+        let mut bubbles = Vec::new();
+        let mut seen = HashSet::new();
+
+        for source in 0..self.unitigs.len() {
+            for &strand in &strands {
+                let mut by_sink: HashMap<(usize, Strand), Vec<(usize, Strand)>> = HashMap::new();
+
+                for (branch, branch_strand) in self.successors(source, strand) {
+                    if self.unitigs[branch].kmers.len() > max_kmers
+                        || self.predecessors(branch, branch_strand).len() != 1
+                    {
+                        continue;
+                    }
+
+                    let sinks = self.successors(branch, branch_strand);
+                    if sinks.len() == 1 {
+                        by_sink
+                            .entry(sinks[0])
+                            .or_insert_with(Vec::new)
+                            .push((branch, branch_strand));
+                    }
+                }
+
+                for (sink, mut branches) in by_sink {
+                    if branches.len() < 2 {
+                        continue;
+                    }
+
+                    // Canonical bubbles are found again from the sink on the
+                    // opposite strand.
+                    let key = ((source, strand), sink);
+                    let flipped = ((sink.0, flip(sink.1)), (source, flip(strand)));
+                    if self.mode == GraphMode::Canonical && !seen.insert(key.min(flipped)) {
+                        continue;
+                    }
+
+                    branches.sort();
+                    bubbles.push(Bubble {
+                        source: (source, strand),
+                        sink: sink,
+                        branches: branches,
+                    });
+                }
+            }
+        }
+
+        bubbles.sort_by(|a, b| (a.source, a.sink).cmp(&(b.source, b.sink)));
+        bubbles
+    }
+}
+
+impl<A, K> UnitigGraph<A, K>
+where
+    A: Alphabet + Complement<Compl = A> + Into<char>,
+    K: Unsigned,
+{
+    /// Write the unitigs and their links as GFA 1.
+    ///
+    /// Segments are named by their index, and carry their length (`LN`) and
+    /// total kmer count (`KC`). Links overlap by `k - 1` bases.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::alphabet::DNA4;
+    /// use seqrs::alphabet::DNA4::*;
+    /// use seqrs::debruijn::{DeBruijnGraph, GraphMode};
+    /// use typenum::U3;
+    ///
+    /// let mut graph: DeBruijnGraph<DNA4, U3> = DeBruijnGraph::new(GraphMode::Forward);
+    /// graph.add_sequence(&[A, A, C, G, A]);
+    /// graph.add_sequence(&[T, A, C, G, T]);
+    ///
+    /// let mut gfa = Vec::new();
+    /// graph.unitigs().write_gfa(&mut gfa).unwrap();
+    /// let gfa = String::from_utf8(gfa).unwrap();
+    ///
+    /// assert_eq!(gfa, "H\tVN:Z:1.0\n\
+    ///     S\t0\tAAC\tLN:i:3\tKC:i:1\n\
+    ///     S\t1\tACG\tLN:i:3\tKC:i:2\n\
+    ///     S\t2\tCGA\tLN:i:3\tKC:i:1\n\
+    ///     S\t3\tCGT\tLN:i:3\tKC:i:1\n\
+    ///     S\t4\tTAC\tLN:i:3\tKC:i:1\n\
+    ///     L\t0\t+\t1\t+\t2M\n\
+    ///     L\t1\t+\t2\t+\t2M\n\
+    ///     L\t1\t+\t3\t+\t2M\n\
+    ///     L\t4\t+\t1\t+\t2M\n");
+    /// ```
+    pub fn write_gfa<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(writer, "H\tVN:Z:1.0")?;
+
+        for (i, unitig) in self.unitigs.iter().enumerate() {
+            let seq: String = unitig.sequence().into_iter().map(|b| b.into()).collect();
+            writeln!(
+                writer,
+                "S\t{}\t{}\tLN:i:{}\tKC:i:{}",
+                i,
+                seq,
+                unitig.len(),
+                unitig.kmer_count
+            )?;
+        }
+
+        for link in &self.links {
+            writeln!(
+                writer,
+                "L\t{}\t{}\t{}\t{}\t{}M",
+                link.from,
+                strand_char(link.from_strand),
+                link.to,
+                strand_char(link.to_strand),
+                K::to_usize() - 1
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[inline]
+fn strand_char(strand: Strand) -> char {
+    match strand {
+        Strand::Forward => '+',
+        Strand::Reverse => '-',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::DNA4;
+    use crate::alphabet::DNA4::*;
+    use crate::complement::IntoReverseComplement;
+    use proptest::collection::vec;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+    use typenum::{U3, U5};
+
+    fn kmer(bases: &[DNA4]) -> SimpleKmer<DNA4, U3> {
+        SimpleKmer::from_bases(bases).unwrap()
+    }
+
+    #[test]
+    fn test_successors() {
+        let mut graph: DeBruijnGraph<DNA4, U3> = DeBruijnGraph::new(GraphMode::Canonical);
+        graph.add_sequence(&[A, A, C, C]);
+
+        assert_eq!(graph.successors(&kmer(&[A, A, C])), vec![kmer(&[A, C, C])]);
+        assert_eq!(graph.predecessors(&kmer(&[A, C, C])), vec![kmer(&[A, A, C])]);
+        assert!(graph.successors(&kmer(&[A, C, C])).is_empty());
+
+        // Walking along the reverse strand.
+        assert_eq!(graph.successors(&kmer(&[G, G, T])), vec![kmer(&[G, T, T])]);
+        assert_eq!(graph.edges().len(), 1);
+    }
+
+    #[test]
+    fn test_linear_unitig() {
+        let seq = vec![A, A, C, G, C, T, T, A, G];
+        let mut graph: DeBruijnGraph<DNA4, U3> = DeBruijnGraph::new(GraphMode::Forward);
+        graph.add_sequence(&seq);
+
+        let unitigs = graph.unitigs();
+        assert_eq!(unitigs.unitigs.len(), 1);
+        assert_eq!(unitigs.unitigs[0].sequence(), seq);
+        assert_eq!(unitigs.unitigs[0].coverage, 1.0);
+        assert_eq!(unitigs.unitigs[0].kmer_count, seq.len() as u64 - 2);
+        assert!(unitigs.links.is_empty());
+    }
+
+    #[test]
+    fn test_tips_and_bubbles() {
+        let mut graph: DeBruijnGraph<DNA4, U5> = DeBruijnGraph::new(GraphMode::Forward);
+        let main = vec![A, A, A, C, A, G, A, T, C, C, C, G, C, T, G, G, G, T];
+        // A single substitution makes a bubble.
+        let mut variant = main.clone();
+        variant[8] = G;
+        // A branch off the end makes a tip.
+        let tip = vec![T, C, C, C, G, C, T, T, A];
+
+        graph.add_sequence(&main);
+        graph.add_sequence(&main);
+        graph.add_sequence(&variant);
+        graph.add_sequence(&tip);
+
+        let unitigs = graph.unitigs();
+        let bubbles = unitigs.bubbles(5);
+        assert_eq!(bubbles.len(), 1);
+        assert_eq!(bubbles[0].branches.len(), 2);
+
+        let covs: Vec<f64> = bubbles[0]
+            .branches
+            .iter()
+            .map(|&(u, _)| unitigs.unitigs[u].coverage)
+            .collect();
+        assert!(covs.contains(&1.0));
+        assert!(covs.iter().any(|&c| c > 2.0));
+
+        // The ends of the main sequence are also tips if they're short.
+        let tips = unitigs.tips(2);
+        assert_eq!(tips.len(), 1);
+        assert_eq!(unitigs.unitigs[tips[0]].sequence(), vec![C, G, C, T, T, A]);
+        assert_eq!(unitigs.tips(4).len(), 3);
+    }
+
+    proptest! {
+        // Every kmer ends up in exactly one unitig, and unitigs spell out
+        // sequences made of graph kmers.
+        #[test]
+        fn test_unitigs_partition_nodes(
+            seqs in vec(vec(select(DNA4::variants()), 0..40), 1..4),
+            canonical in proptest::bool::ANY,
+        ) {
+            let mode = if canonical { GraphMode::Canonical } else { GraphMode::Forward };
+            let mut graph: DeBruijnGraph<DNA4, U5> = DeBruijnGraph::new(mode);
+            for seq in &seqs {
+                graph.add_sequence(seq);
+            }
+
+            let unitigs = graph.unitigs();
+            let mut seen = HashSet::new();
+            for unitig in &unitigs.unitigs {
+                let kmers: Vec<SimpleKmer<DNA4, U5>> =
+                    unitig.sequence().iter().kmers::<U5>().collect();
+                assert_eq!(&kmers, &unitig.kmers);
+
+                for k in kmers {
+                    assert!(graph.contains(&k));
+                    assert!(seen.insert(graph.key(k)));
+                }
+            }
+            assert_eq!(seen.len(), graph.len());
+
+            // Links join kmers that are adjacent in the graph.
+            for link in &unitigs.links {
+                let from = unitigs.unitigs[link.from].last(link.from_strand);
+                let to = unitigs.unitigs[link.to].first(link.to_strand);
+                assert!(graph.successors(&from).contains(&to));
+            }
+        }
+
+        #[test]
+        fn test_canonical_graph_strand_independent(seq in vec(select(DNA4::variants()), 0..60)) {
+            let rc: Vec<DNA4> = seq.iter().reverse_complement().collect();
+
+            let mut a: DeBruijnGraph<DNA4, U5> = DeBruijnGraph::new(GraphMode::Canonical);
+            a.add_sequence(&seq);
+            let mut b: DeBruijnGraph<DNA4, U5> = DeBruijnGraph::new(GraphMode::Canonical);
+            b.add_sequence(&rc);
+
+            assert_eq!(a.edges(), b.edges());
+            assert_eq!(a.unitigs().unitigs, b.unitigs().unitigs);
+        }
+    }
+}
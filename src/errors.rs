@@ -11,8 +11,10 @@ pub struct SeqError {
 
 #[derive(Debug, Fail, PartialEq, Eq, Clone)]
 pub enum SeqErrorKind {
-    #[fail(display = "Encountered unknown character in alphabet {}", base)]
-    AlphabetReadError { base: char },
+    /// `position` is the offset of the character when parsing whole
+    /// sequences, and `Position(None)` when parsing a single character.
+    #[fail(display = "Encountered unknown character in alphabet {}{}", base, position)]
+    AlphabetReadError { base: char, position: Position },
     #[fail(display = "String must contain 3 characters to be parsed into codon.")]
     CodonFromStrTooShort,
     #[fail(
@@ -20,6 +22,51 @@ pub enum SeqErrorKind {
         base
     )]
     RedundantAlphabetConversionError { base: char },
+    #[fail(
+        display = "Spaced seed {} must only contain 0 and 1, and must start and end with 1.",
+        pattern
+    )]
+    SpacedSeedReadError { pattern: String },
+    #[fail(display = "Sketches must use the same kmer size and sketch type to be compared.")]
+    IncompatibleSketches,
+    #[fail(display = "Encountered unknown three letter amino acid code {}", code)]
+    ThreeLetterCodeReadError { code: String },
+    #[fail(display = "Encountered invalid quality character {}", qual)]
+    QualityReadError { qual: char },
+    #[fail(
+        display = "Sequence of length {} has {} quality scores.",
+        seq, qual
+    )]
+    QualityLengthError { seq: usize, qual: usize },
+    #[fail(display = "Encountered invalid base modification {}", code)]
+    ModificationReadError { code: String },
+    #[fail(display = "Unbalanced bracket at position {}.", position)]
+    UnbalancedBracketError { position: usize },
+    #[fail(
+        display = "Sequence of length {} has a structure of length {}.",
+        seq, structure
+    )]
+    StructureLengthError { seq: usize, structure: usize },
+    #[fail(
+        display = "Bases at {} and {} are not a canonical pair.",
+        left, right
+    )]
+    NonCanonicalPairError { left: usize, right: usize },
+}
+
+/// The offset of an unknown character, if it's known.
+///
+/// This only exists so that the offset can be shown in error messages.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Position(pub Option<usize>);
+
+impl Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(p) => write!(f, " at position {}", p),
+            None => Ok(()),
+        }
+    }
 }
 
 impl Fail for SeqError {
@@ -42,6 +89,19 @@ impl SeqError {
     pub fn kind(&self) -> &SeqErrorKind {
         self.inner.get_context()
     }
+
+    /// Record the offset of an unknown character when parsing a whole
+    /// sequence. Other errors are returned unchanged.
+    pub(crate) fn at_position(self, position: usize) -> Self {
+        match *self.kind() {
+            SeqErrorKind::AlphabetReadError { base, .. } => SeqErrorKind::AlphabetReadError {
+                base: base,
+                position: Position(Some(position)),
+            }
+            .into(),
+            _ => self,
+        }
+    }
 }
 
 impl From<SeqErrorKind> for SeqError {
@@ -57,3 +117,26 @@ impl From<Context<SeqErrorKind>> for SeqError {
         SeqError { inner: inner }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alphabet_read_error_display() {
+        let err = SeqErrorKind::AlphabetReadError {
+            base: 'N',
+            position: Position(None),
+        };
+        assert_eq!(err.to_string(), "Encountered unknown character in alphabet N");
+
+        let err = SeqError::from(SeqErrorKind::AlphabetReadError {
+            base: 'N',
+            position: Position(Some(3)),
+        });
+        assert_eq!(
+            err.to_string(),
+            "Encountered unknown character in alphabet N at position 3"
+        );
+    }
+}
@@ -0,0 +1,386 @@
+//! Probabilistic kmer sets for when exact counting would take too much
+//! memory.
+//!
+//! Both filters are blocked: all of the bits (or counters) for a kmer are
+//! kept in a single block of 512 slots, so the bits for a query share a
+//! single cache line, and the counters a small group of them.
+//! This costs a slightly higher false positive rate than a classic Bloom
+//! filter of the same size.
+
+use std::f64::consts::LN_2;
+use std::marker::PhantomData;
+
+use crate::alphabet::Alphabet;
+use crate::kmer::InvertibleHash;
+
+/// The number of bits in a block of a `BloomFilter`.
+const BLOCK_BITS: usize = 512;
+const BLOCK_WORDS: usize = BLOCK_BITS / 64;
+
+/// The number of counters in a block of a `CountingBloomFilter`.
+/// Smaller blocks fill too unevenly to keep the false positive rate down.
+const BLOCK_COUNTERS: usize = 512;
+
+/// Find the number of slots and hash functions needed to store `expected`
+/// items with a false positive rate of `fpr`.
+fn optimal_parameters(expected: usize, fpr: f64) -> (usize, usize) {
+    assert!(fpr > 0.0 && fpr < 1.0, "False positive rate must be between 0 and 1.");
+
+    let n = expected.max(1) as f64;
+    let m = (-n * fpr.ln() / (LN_2 * LN_2)).ceil();
+    let k = ((m / n) * LN_2).round().max(1.0);
+    (m as usize, k as usize)
+}
+
+/// Split a kmer rank into a block index and the seeds for the positions
+/// within the block.
+#[inline]
+fn hash_rank(rank: usize, n_blocks: usize) -> (usize, u32, u32) {
+    let hasher = InvertibleHash::default();
+    let h1 = hasher.hash(rank as u64);
+    let h2 = hasher.hash(h1 ^ 0x9e37_79b9_7f4a_7c15);
+
+    let block = ((h1 >> 32) as usize) % n_blocks;
+    // An odd step visits every position before repeating.
+    (block, h2 as u32, ((h2 >> 32) as u32) | 1)
+}
+
+/// Iterate over the positions within a block for a kmer.
+#[inline]
+fn positions(start: u32, step: u32, n_hashes: usize, size: usize) -> impl Iterator<Item = usize> {
+    (0..n_hashes as u32).map(move |i| (start.wrapping_add(i.wrapping_mul(step)) as usize) % size)
+}
+
+/// A blocked Bloom filter over kmers (or any other alphabet).
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::DNA4;
+/// use seqrs::alphabet::DNA4::*;
+/// use seqrs::kmer::{BloomFilter, IntoKmers, SimpleKmer};
+/// use typenum::U3;
+///
+/// let mut filter: BloomFilter<SimpleKmer<DNA4, U3>> = BloomFilter::with_rate(100, 0.01);
+/// filter.extend(vec![A, C, G, T].iter().kmers::<U3>());
+///
+/// let acg: SimpleKmer<DNA4, U3> = vec![A, C, G].iter().kmers::<U3>().next().unwrap();
+/// assert!(filter.contains(&acg));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter<K> {
+    blocks: Vec<[u64; BLOCK_WORDS]>,
+    n_hashes: usize,
+    kmer: PhantomData<K>,
+}
+
+impl<K: Alphabet> BloomFilter<K> {
+    /// Create a filter with at least `n_bits` bits, setting `n_hashes` bits
+    /// per kmer.
+    pub fn new(n_bits: usize, n_hashes: usize) -> Self {
+        assert!(n_hashes > 0, "Bloom filters need at least one hash function.");
+        let n_blocks = ((n_bits + BLOCK_BITS - 1) / BLOCK_BITS).max(1);
+
+        BloomFilter {
+            blocks: vec![[0; BLOCK_WORDS]; n_blocks],
+            n_hashes: n_hashes,
+            kmer: PhantomData,
+        }
+    }
+
+    /// Create a filter sized to hold `expected` kmers with a false positive
+    /// rate of about `fpr`.
+    pub fn with_rate(expected: usize, fpr: f64) -> Self {
+        let (n_bits, n_hashes) = optimal_parameters(expected, fpr);
+        Self::new(n_bits, n_hashes)
+    }
+
+    /// The number of bits in the filter.
+    pub fn n_bits(&self) -> usize {
+        self.blocks.len() * BLOCK_BITS
+    }
+
+    /// The number of bits set for each kmer.
+    pub fn n_hashes(&self) -> usize {
+        self.n_hashes
+    }
+
+    /// Add a kmer to the filter.
+    #[inline]
+    pub fn insert(&mut self, kmer: &K) {
+        let (block, start, step) = hash_rank(kmer.rank(), self.blocks.len());
+        let block = &mut self.blocks[block];
+
+        for p in positions(start, step, self.n_hashes, BLOCK_BITS) {
+            block[p / 64] |= 1 << (p % 64);
+        }
+    }
+
+    /// Returns true if the kmer may have been added, and false if it
+    /// definitely hasn't.
+    #[inline]
+    pub fn contains(&self, kmer: &K) -> bool {
+        let (block, start, step) = hash_rank(kmer.rank(), self.blocks.len());
+        let block = &self.blocks[block];
+
+        positions(start, step, self.n_hashes, BLOCK_BITS).all(|p| block[p / 64] & (1 << (p % 64)) != 0)
+    }
+
+    /// The fraction of bits that are set.
+    pub fn fill_ratio(&self) -> f64 {
+        let set: u64 = self
+            .blocks
+            .iter()
+            .flat_map(|b| b.iter())
+            .map(|w| u64::from(w.count_ones()))
+            .sum();
+        set as f64 / self.n_bits() as f64
+    }
+
+    /// Estimate the current false positive rate from the fill ratio.
+    pub fn estimated_fpr(&self) -> f64 {
+        self.fill_ratio().powi(self.n_hashes as i32)
+    }
+
+    /// Add all kmers from another filter with the same parameters.
+    pub fn union(&mut self, other: &Self) {
+        assert_eq!(self.blocks.len(), other.blocks.len(), "Filters must be the same size.");
+        assert_eq!(self.n_hashes, other.n_hashes, "Filters must use the same hashes.");
+
+        for (a, b) in self.blocks.iter_mut().zip(other.blocks.iter()) {
+            for (x, y) in a.iter_mut().zip(b.iter()) {
+                *x |= *y;
+            }
+        }
+    }
+}
+
+impl<K: Alphabet> Extend<K> for BloomFilter<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for kmer in iter {
+            self.insert(&kmer);
+        }
+    }
+}
+
+/// A blocked counting Bloom filter, storing an approximate count for each
+/// kmer.
+///
+/// Counts are never underestimated, but may be overestimated when kmers
+/// share counters. Counters saturate at 255.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::DNA4;
+/// use seqrs::alphabet::DNA4::*;
+/// use seqrs::kmer::{CountingBloomFilter, IntoKmers, SimpleKmer};
+/// use typenum::U2;
+///
+/// let mut filter: CountingBloomFilter<SimpleKmer<DNA4, U2>> =
+///     CountingBloomFilter::with_rate(100, 0.01);
+/// filter.extend(vec![A, C, A, C, A].iter().kmers::<U2>());
+///
+/// let ac: SimpleKmer<DNA4, U2> = vec![A, C].iter().kmers::<U2>().next().unwrap();
+/// assert!(filter.count(&ac) >= 2);
+///
+/// // Only keep kmers seen at least twice.
+/// assert!(filter.is_solid(&ac, 2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountingBloomFilter<K> {
+    counters: Vec<u8>,
+    n_hashes: usize,
+    kmer: PhantomData<K>,
+}
+
+impl<K: Alphabet> CountingBloomFilter<K> {
+    /// Create a filter with at least `n_counters` counters, using
+    /// `n_hashes` counters per kmer.
+    pub fn new(n_counters: usize, n_hashes: usize) -> Self {
+        assert!(n_hashes > 0, "Bloom filters need at least one hash function.");
+        let n_blocks = ((n_counters + BLOCK_COUNTERS - 1) / BLOCK_COUNTERS).max(1);
+
+        CountingBloomFilter {
+            counters: vec![0; n_blocks * BLOCK_COUNTERS],
+            n_hashes: n_hashes,
+            kmer: PhantomData,
+        }
+    }
+
+    /// Create a filter sized to hold `expected` distinct kmers, where the
+    /// chance of a kmer that wasn't added having a non-zero count is about
+    /// `fpr`.
+    pub fn with_rate(expected: usize, fpr: f64) -> Self {
+        let (n_counters, n_hashes) = optimal_parameters(expected, fpr);
+        Self::new(n_counters, n_hashes)
+    }
+
+    /// The number of counters in the filter.
+    pub fn n_counters(&self) -> usize {
+        self.counters.len()
+    }
+
+    /// The number of counters used for each kmer.
+    pub fn n_hashes(&self) -> usize {
+        self.n_hashes
+    }
+
+    #[inline]
+    fn slots(&self, kmer: &K) -> impl Iterator<Item = usize> {
+        let n_blocks = self.counters.len() / BLOCK_COUNTERS;
+        let (block, start, step) = hash_rank(kmer.rank(), n_blocks);
+        let offset = block * BLOCK_COUNTERS;
+
+        positions(start, step, self.n_hashes, BLOCK_COUNTERS).map(move |p| offset + p)
+    }
+
+    /// Increment the count of a kmer.
+    #[inline]
+    pub fn insert(&mut self, kmer: &K) {
+        for slot in self.slots(kmer) {
+            self.counters[slot] = self.counters[slot].saturating_add(1);
+        }
+    }
+
+    /// Decrement the count of a kmer, if it appears to be present.
+    ///
+    /// Removing kmers that were never added corrupts the counts of other
+    /// kmers, and saturated counters are left alone.
+    pub fn remove(&mut self, kmer: &K) {
+        if self.count(kmer) == 0 {
+            return;
+        }
+
+        for slot in self.slots(kmer) {
+            if self.counters[slot] < u8::max_value() {
+                self.counters[slot] -= 1;
+            }
+        }
+    }
+
+    /// An upper bound on the number of times a kmer was added.
+    #[inline]
+    pub fn count(&self, kmer: &K) -> u8 {
+        self.slots(kmer)
+            .map(|slot| self.counters[slot])
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Returns true if the kmer may have been added.
+    pub fn contains(&self, kmer: &K) -> bool {
+        self.count(kmer) > 0
+    }
+
+    /// Returns true if the kmer appears to have been added at least
+    /// `min_count` times.
+    pub fn is_solid(&self, kmer: &K, min_count: u8) -> bool {
+        self.count(kmer) >= min_count
+    }
+}
+
+impl<K: Alphabet> Extend<K> for CountingBloomFilter<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for kmer in iter {
+            self.insert(&kmer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::DNA4;
+    use crate::kmer::SimpleKmer;
+    use proptest::collection::vec;
+    use proptest::{proptest, proptest_helper};
+    use typenum::U12;
+
+    type K12 = SimpleKmer<DNA4, U12>;
+
+    fn kmer(rank: usize) -> K12 {
+        K12::from_rank(rank).unwrap()
+    }
+
+    #[test]
+    fn test_optimal_parameters() {
+        // The textbook values for 1% are ~9.6 bits per item and 7 hashes.
+        let (m, k) = optimal_parameters(1000, 0.01);
+        assert_eq!(m, 9586);
+        assert_eq!(k, 7);
+    }
+
+    #[test]
+    fn test_false_positive_rate() {
+        let mut filter: BloomFilter<K12> = BloomFilter::with_rate(10_000, 0.01);
+        let mut counting: CountingBloomFilter<K12> = CountingBloomFilter::with_rate(10_000, 0.01);
+
+        for r in 0..10_000 {
+            filter.insert(&kmer(r * 3));
+            counting.insert(&kmer(r * 3));
+        }
+
+        let n_tests = 20_000;
+        let fp = (0..n_tests)
+            .filter(|r| filter.contains(&kmer(r * 3 + 1)))
+            .count();
+        let counting_fp = (0..n_tests)
+            .filter(|r| counting.contains(&kmer(r * 3 + 1)))
+            .count();
+
+        // Blocking costs a little accuracy, so allow some slack.
+        assert!((fp as f64 / n_tests as f64) < 0.02);
+        assert!((counting_fp as f64 / n_tests as f64) < 0.02);
+        assert!(filter.estimated_fpr() < 0.02);
+    }
+
+    #[test]
+    fn test_counting_remove() {
+        let mut filter: CountingBloomFilter<K12> = CountingBloomFilter::with_rate(100, 0.001);
+        let k = kmer(42);
+        filter.insert(&k);
+        filter.insert(&k);
+        assert_eq!(filter.count(&k), 2);
+
+        filter.remove(&k);
+        assert_eq!(filter.count(&k), 1);
+        filter.remove(&k);
+        filter.remove(&k);
+        assert!(!filter.contains(&k));
+    }
+
+    #[test]
+    fn test_counting_saturates() {
+        let mut filter: CountingBloomFilter<K12> = CountingBloomFilter::new(64, 3);
+        let k = kmer(7);
+        for _ in 0..300 {
+            filter.insert(&k);
+        }
+        assert_eq!(filter.count(&k), u8::max_value());
+    }
+
+    proptest! {
+        #[test]
+        fn test_no_false_negatives(
+            ranks in vec(0_usize..(1 << 24), 0..200),
+            split in 0_usize..200,
+        ) {
+            let split = split.min(ranks.len());
+            let mut a: BloomFilter<K12> = BloomFilter::with_rate(200, 0.05);
+            let mut b: BloomFilter<K12> = BloomFilter::with_rate(200, 0.05);
+            let mut counting: CountingBloomFilter<K12> = CountingBloomFilter::with_rate(200, 0.05);
+
+            a.extend(ranks[..split].iter().map(|&r| kmer(r)));
+            b.extend(ranks[split..].iter().map(|&r| kmer(r)));
+            counting.extend(ranks.iter().map(|&r| kmer(r)));
+            a.union(&b);
+
+            for &r in &ranks {
+                let n = ranks.iter().filter(|&&x| x == r).count();
+                assert!(a.contains(&kmer(r)));
+                assert!(counting.count(&kmer(r)) as usize >= n);
+            }
+        }
+    }
+}
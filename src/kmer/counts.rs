@@ -0,0 +1,537 @@
+//! Exact kmer counting.
+//!
+//! [`KmerCounts`] stores the number of times each kmer was seen, using a
+//! dense array indexed by rank when the number of possible kmers is small
+//! and a hash map otherwise.
+//!
+//! [`KmerCounts`]: struct.KmerCounts.html
+
+use std::collections::hash_map;
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::io::{Read, Write};
+use std::iter::{Enumerate, FromIterator};
+use std::marker::PhantomData;
+use std::slice;
+
+use crate::alphabet::Alphabet;
+
+/// Alphabets with at most this many variants are counted in a dense array.
+/// This is 4^12, i.e. DNA4 12-mers, which takes 64 MiB of counts.
+pub const DENSE_LIMIT: usize = 1 << 24;
+
+/// Identifies the binary count format.
+const MAGIC: &[u8; 4] = b"SQKC";
+const VERSION: u8 = 1;
+
+#[derive(Debug, Clone)]
+enum Storage {
+    Dense(Vec<u32>),
+    Sparse(HashMap<usize, u32>),
+}
+
+/// A table of exact kmer counts.
+///
+/// Counts saturate at `u32::max_value()` rather than overflowing.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::DNA4;
+/// use seqrs::alphabet::DNA4::*;
+/// use seqrs::kmer::{IntoKmers, Kmer, KmerCounts, SimpleKmer};
+/// use typenum::U2;
+///
+/// let seq = vec![A, C, A, C, A];
+/// let counts: KmerCounts<SimpleKmer<DNA4, U2>> = seq.iter().kmers::<U2>().collect();
+///
+/// let ac = SimpleKmer::from_bases(&[A, C]).unwrap();
+/// let ca = SimpleKmer::from_bases(&[C, A]).unwrap();
+/// assert_eq!(counts.get(&ac), 2);
+/// assert_eq!(counts.get(&ca), 2);
+/// assert_eq!(counts.len(), 2);
+/// assert_eq!(counts.total(), 4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KmerCounts<K> {
+    storage: Storage,
+    kmer: PhantomData<K>,
+}
+
+impl<K: Alphabet> KmerCounts<K> {
+    /// Create an empty table, using dense storage if the cardinality of the
+    /// kmer is at most [`DENSE_LIMIT`].
+    ///
+    /// [`DENSE_LIMIT`]: constant.DENSE_LIMIT.html
+    pub fn new() -> Self {
+        if K::cardinality() <= DENSE_LIMIT {
+            Self::dense()
+        } else {
+            Self::sparse()
+        }
+    }
+
+    /// Create an empty table backed by an array with an entry for every
+    /// possible kmer.
+    pub fn dense() -> Self {
+        KmerCounts {
+            storage: Storage::Dense(vec![0; K::cardinality()]),
+            kmer: PhantomData,
+        }
+    }
+
+    /// Create an empty table backed by a hash map.
+    pub fn sparse() -> Self {
+        KmerCounts {
+            storage: Storage::Sparse(HashMap::new()),
+            kmer: PhantomData,
+        }
+    }
+
+    /// Returns true if the table is backed by an array.
+    pub fn is_dense(&self) -> bool {
+        match self.storage {
+            Storage::Dense(_) => true,
+            Storage::Sparse(_) => false,
+        }
+    }
+
+    /// Increment the count of a kmer by one.
+    #[inline]
+    pub fn add(&mut self, kmer: &K) {
+        self.add_count(kmer, 1)
+    }
+
+    /// Increment the count of a kmer by `n`.
+    #[inline]
+    pub fn add_count(&mut self, kmer: &K, n: u32) {
+        self.add_rank(kmer.rank(), n)
+    }
+
+    #[inline]
+    fn add_rank(&mut self, rank: usize, n: u32) {
+        if n == 0 {
+            return;
+        }
+
+        let count = match self.storage {
+            Storage::Dense(ref mut v) => &mut v[rank],
+            Storage::Sparse(ref mut m) => m.entry(rank).or_insert(0),
+        };
+        *count = count.saturating_add(n);
+    }
+
+    /// Get the number of times a kmer was seen.
+    pub fn get(&self, kmer: &K) -> u32 {
+        let rank = kmer.rank();
+        match self.storage {
+            Storage::Dense(ref v) => v[rank],
+            Storage::Sparse(ref m) => m.get(&rank).cloned().unwrap_or(0),
+        }
+    }
+
+    /// The number of distinct kmers with a non-zero count.
+    pub fn len(&self) -> usize {
+        match self.storage {
+            Storage::Dense(ref v) => v.iter().filter(|&&c| c > 0).count(),
+            Storage::Sparse(ref m) => m.len(),
+        }
+    }
+
+    /// Returns true if no kmers have been counted.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The sum of all counts.
+    pub fn total(&self) -> u64 {
+        self.iter().map(|(_, c)| u64::from(c)).sum()
+    }
+
+    /// Iterate over the kmers with non-zero counts.
+    ///
+    /// Dense tables yield kmers in rank order, sparse tables in an
+    /// arbitrary order.
+    pub fn iter(&self) -> Iter<'_, K> {
+        let inner = match self.storage {
+            Storage::Dense(ref v) => IterInner::Dense(v.iter().enumerate()),
+            Storage::Sparse(ref m) => IterInner::Sparse(m.iter()),
+        };
+
+        Iter {
+            inner: inner,
+            kmer: PhantomData,
+        }
+    }
+
+    /// Add all counts from another table into this one.
+    pub fn merge(&mut self, other: &Self) {
+        for (kmer, count) in other.iter() {
+            self.add_count(&kmer, count);
+        }
+    }
+
+    /// The `n` most frequent kmers, ordered by decreasing count.
+    /// Ties are ordered by rank.
+    pub fn top(&self, n: usize) -> Vec<(K, u32)> {
+        let mut counts: Vec<(K, u32)> = self.iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.rank().cmp(&b.0.rank())));
+        counts.truncate(n);
+        counts
+    }
+
+    /// The kmer spectrum, mapping each count to the number of distinct kmers
+    /// seen that many times.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::alphabet::DNA4;
+    /// use seqrs::alphabet::DNA4::*;
+    /// use seqrs::kmer::{IntoKmers, KmerCounts, SimpleKmer};
+    /// use typenum::U1;
+    ///
+    /// let seq = vec![A, A, A, C, C, G];
+    /// let counts: KmerCounts<SimpleKmer<DNA4, U1>> = seq.iter().kmers::<U1>().collect();
+    /// let spectrum = counts.spectrum();
+    ///
+    /// assert_eq!(spectrum.get(&1), Some(&1));
+    /// assert_eq!(spectrum.get(&2), Some(&1));
+    /// assert_eq!(spectrum.get(&3), Some(&1));
+    /// ```
+    pub fn spectrum(&self) -> BTreeMap<u32, u64> {
+        let mut spectrum = BTreeMap::new();
+        for (_, count) in self.iter() {
+            *spectrum.entry(count).or_insert(0) += 1;
+        }
+        spectrum
+    }
+
+    /// Write the counts in a compact binary format.
+    ///
+    /// The format is a 4 byte magic string, a version byte, and then
+    /// LEB128 encoded integers: the kmer cardinality, the number of entries,
+    /// and for each entry in rank order the difference from the previous
+    /// rank followed by the count.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut entries: Vec<(usize, u32)> = self.iter().map(|(k, c)| (k.rank(), c)).collect();
+        if !self.is_dense() {
+            entries.sort();
+        }
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        write_varint(writer, K::cardinality() as u64)?;
+        write_varint(writer, entries.len() as u64)?;
+
+        let mut last = 0;
+        for (rank, count) in entries {
+            write_varint(writer, (rank - last) as u64)?;
+            write_varint(writer, u64::from(count))?;
+            last = rank;
+        }
+
+        Ok(())
+    }
+
+    /// Read counts written by [`write_to`].
+    ///
+    /// Fails with `InvalidData` if the data isn't a count table, or if it
+    /// was written for kmers with a different cardinality.
+    ///
+    /// [`write_to`]: #method.write_to
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::alphabet::DNA4;
+    /// use seqrs::alphabet::DNA4::*;
+    /// use seqrs::kmer::{IntoKmers, KmerCounts, SimpleKmer};
+    /// use typenum::U3;
+    ///
+    /// let seq = vec![A, C, G, T, A, C, G];
+    /// let counts: KmerCounts<SimpleKmer<DNA4, U3>> = seq.iter().kmers::<U3>().collect();
+    ///
+    /// let mut buffer = Vec::new();
+    /// counts.write_to(&mut buffer).unwrap();
+    /// let read = KmerCounts::read_from(&mut buffer.as_slice()).unwrap();
+    ///
+    /// assert_eq!(counts, read);
+    /// ```
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        let mut version = [0; 1];
+        reader.read_exact(&mut version)?;
+
+        if &magic != MAGIC || version[0] != VERSION {
+            return Err(invalid_data("Not a kmer count table."));
+        }
+
+        if read_varint(reader)? != K::cardinality() as u64 {
+            return Err(invalid_data("Kmer cardinality doesn't match the table."));
+        }
+
+        let n = read_varint(reader)?;
+        let mut counts = Self::new();
+        let mut rank: u64 = 0;
+
+        for _ in 0..n {
+            rank = rank
+                .checked_add(read_varint(reader)?)
+                .filter(|&r| K::from_rank(r as usize).is_some())
+                .ok_or_else(|| invalid_data("Kmer rank is out of range."))?;
+
+            let count = read_varint(reader)?;
+            if count > u64::from(u32::max_value()) {
+                return Err(invalid_data("Kmer count is out of range."));
+            }
+
+            counts.add_rank(rank as usize, count as u32);
+        }
+
+        Ok(counts)
+    }
+}
+
+// Dense and sparse tables are equal if they hold the same counts.
+impl<K: Alphabet> PartialEq for KmerCounts<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, c)| other.get(&k) == c)
+    }
+}
+
+impl<K: Alphabet> Eq for KmerCounts<K> {}
+
+impl<K: Alphabet> Default for KmerCounts<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Alphabet> Extend<K> for KmerCounts<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for kmer in iter {
+            self.add(&kmer);
+        }
+    }
+}
+
+impl<K: Alphabet> FromIterator<K> for KmerCounts<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut counts = Self::new();
+        counts.extend(iter);
+        counts
+    }
+}
+
+impl<'a, K: Alphabet> IntoIterator for &'a KmerCounts<K> {
+    type Item = (K, u32);
+    type IntoIter = Iter<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum IterInner<'a> {
+    Dense(Enumerate<slice::Iter<'a, u32>>),
+    Sparse(hash_map::Iter<'a, usize, u32>),
+}
+
+/// An iterator over the kmers and counts in a [`KmerCounts`].
+///
+/// [`KmerCounts`]: struct.KmerCounts.html
+#[derive(Debug, Clone)]
+pub struct Iter<'a, K> {
+    inner: IterInner<'a>,
+    kmer: PhantomData<K>,
+}
+
+impl<'a, K: Alphabet> Iterator for Iter<'a, K> {
+    type Item = (K, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (rank, count) = match self.inner {
+            IterInner::Dense(ref mut it) => it.find(|(_, &c)| c > 0).map(|(r, &c)| (r, c))?,
+            IterInner::Sparse(ref mut it) => it.next().map(|(&r, &c)| (r, c))?,
+        };
+
+        // Ranks only come from valid kmers.
+        Some((unsafe { K::from_rank_unsafe(rank) }, count))
+    }
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Write an unsigned LEB128 integer.
+fn write_varint<W: Write>(writer: &mut W, mut value: u64) -> io::Result<()> {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            return writer.write_all(&[byte]);
+        }
+        writer.write_all(&[byte | 0x80])?;
+    }
+}
+
+/// Read an unsigned LEB128 integer.
+fn read_varint<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut value: u64 = 0;
+    let mut shift = 0;
+    let mut byte = [0; 1];
+
+    loop {
+        reader.read_exact(&mut byte)?;
+        if shift >= 64 || (shift == 63 && byte[0] > 1) {
+            return Err(invalid_data("Integer is too large."));
+        }
+
+        value |= u64::from(byte[0] & 0x7f) << shift;
+        if byte[0] & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::DNA4;
+    use crate::alphabet::DNA4::*;
+    use crate::kmer::{IntoKmers, Kmer, SimpleKmer};
+    use proptest::collection::vec;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+    use typenum::{U2, U13, U32};
+
+    type K2 = SimpleKmer<DNA4, U2>;
+
+    #[test]
+    fn test_storage_choice() {
+        assert!(KmerCounts::<K2>::new().is_dense());
+        assert!(!KmerCounts::<SimpleKmer<DNA4, U13>>::new().is_dense());
+    }
+
+    // The number of DNA4 32-mers doesn't fit in a usize.
+    #[test]
+    fn test_largest_kmers() {
+        let mut counts = KmerCounts::<SimpleKmer<DNA4, U32>>::new();
+        assert!(!counts.is_dense());
+
+        let kmer = SimpleKmer::<DNA4, U32>::from_bases(vec![T; 32]).unwrap();
+        counts.add(&kmer);
+        assert_eq!(counts.get(&kmer), 1);
+        assert_eq!(counts.iter().collect::<Vec<_>>(), vec![(kmer, 1)]);
+
+        let mut buf = Vec::new();
+        counts.write_to(&mut buf).unwrap();
+        let read = KmerCounts::read_from(&mut buf.as_slice()).unwrap();
+        assert_eq!(counts, read);
+    }
+
+    #[test]
+    fn test_top() {
+        let seq = vec![A, A, A, A, C, G, C, G];
+        let counts: KmerCounts<K2> = seq.iter().kmers::<U2>().collect();
+        let top: Vec<(Vec<DNA4>, u32)> = counts
+            .top(3)
+            .into_iter()
+            .map(|(k, c)| (k.bases(), c))
+            .collect();
+
+        assert_eq!(
+            top,
+            vec![(vec![A, A], 3), (vec![C, G], 2), (vec![A, C], 1)]
+        );
+    }
+
+    #[test]
+    fn test_saturating() {
+        let mut counts: KmerCounts<K2> = KmerCounts::new();
+        let kmer = K2::from_rank(3).unwrap();
+        counts.add_count(&kmer, u32::max_value());
+        counts.add(&kmer);
+        assert_eq!(counts.get(&kmer), u32::max_value());
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let counts: KmerCounts<K2> = vec![A, C, G].iter().kmers::<U2>().collect();
+        let mut buffer = Vec::new();
+        counts.write_to(&mut buffer).unwrap();
+
+        // Wrong kmer size.
+        let err = KmerCounts::<SimpleKmer<DNA4, U13>>::read_from(&mut buffer.as_slice());
+        assert_eq!(err.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        // Truncated.
+        buffer.pop();
+        let err = KmerCounts::<K2>::read_from(&mut buffer.as_slice());
+        assert_eq!(err.unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+        let err = KmerCounts::<K2>::read_from(&mut &b"NOPE\x01"[..]);
+        assert_eq!(err.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    proptest! {
+        #[test]
+        fn test_dense_equals_sparse(
+            a in vec(select(DNA4::variants()), 0..100),
+            b in vec(select(DNA4::variants()), 0..100),
+        ) {
+            let mut dense: KmerCounts<K2> = KmerCounts::dense();
+            let mut sparse: KmerCounts<K2> = KmerCounts::sparse();
+            dense.extend(a.iter().kmers::<U2>());
+            sparse.extend(b.iter().kmers::<U2>());
+
+            let mut dense2: KmerCounts<K2> = KmerCounts::dense();
+            dense2.extend(b.iter().kmers::<U2>());
+            let mut sparse2: KmerCounts<K2> = KmerCounts::sparse();
+            sparse2.extend(a.iter().kmers::<U2>());
+
+            dense.merge(&dense2);
+            sparse.merge(&sparse2);
+
+            for kmer in K2::variants() {
+                assert_eq!(dense.get(&kmer), sparse.get(&kmer));
+            }
+            assert_eq!(dense.len(), sparse.len());
+            let n_kmers = a.len().saturating_sub(1) + b.len().saturating_sub(1);
+            assert_eq!(dense.total(), n_kmers as u64);
+            assert_eq!(dense.spectrum(), sparse.spectrum());
+            assert_eq!(dense.top(5), sparse.top(5));
+        }
+
+        #[test]
+        fn test_serialisation_roundtrip(
+            seq in vec(select(DNA4::variants()), 0..100),
+            dense in proptest::bool::ANY,
+        ) {
+            let mut counts: KmerCounts<K2> = if dense {
+                KmerCounts::dense()
+            } else {
+                KmerCounts::sparse()
+            };
+            counts.extend(seq.iter().kmers::<U2>());
+
+            let mut buffer = Vec::new();
+            counts.write_to(&mut buffer).unwrap();
+            let read: KmerCounts<K2> = KmerCounts::read_from(&mut buffer.as_slice()).unwrap();
+
+            assert_eq!(counts, read);
+        }
+
+        #[test]
+        fn test_varint_roundtrip(value in proptest::num::u64::ANY) {
+            let mut buffer = Vec::new();
+            write_varint(&mut buffer, value).unwrap();
+            assert_eq!(read_varint(&mut buffer.as_slice()).unwrap(), value);
+        }
+    }
+}
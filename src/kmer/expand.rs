@@ -0,0 +1,376 @@
+//! Kmers from sequences containing redundant bases.
+//!
+//! A window containing a redundant base like `R` (`A` or `G`) stands for
+//! several concrete kmers. Windows can be skipped, expanded into every
+//! concrete kmer that they represent, or only expanded when that number is
+//! small enough.
+
+use std::borrow::Borrow;
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+use typenum::Unsigned;
+
+use crate::alphabet::{Alphabet, AA, DNA, DNA4, RNA, RNA4};
+use crate::kmer::SimpleKmer;
+
+/// Alphabets whose letters can be expanded into the non-redundant letters
+/// that they represent.
+pub trait Expand: Alphabet {
+    /// The alphabet of the expanded letters.
+    type Concrete: Alphabet;
+
+    /// Returns every non-redundant letter that this letter represents.
+    fn expand(&self) -> Vec<Self::Concrete>;
+}
+
+impl Expand for DNA4 {
+    type Concrete = DNA4;
+
+    fn expand(&self) -> Vec<DNA4> {
+        vec![*self]
+    }
+}
+
+impl Expand for DNA {
+    type Concrete = DNA4;
+
+    /// Expands into the [`DNA4`] bases that the base matches.
+    ///
+    /// [`DNA4`]: ../alphabet/enum.DNA4.html
+    fn expand(&self) -> Vec<DNA4> {
+        let matches = self.redundant_matches();
+        if matches.is_empty() {
+            vec![DNA4::try_from(self).expect("Non-redundant DNA is DNA4.")]
+        } else {
+            matches
+                .iter()
+                .map(|b| DNA4::try_from(b).expect("Non-redundant DNA is DNA4."))
+                .collect()
+        }
+    }
+}
+
+impl Expand for RNA4 {
+    type Concrete = RNA4;
+
+    fn expand(&self) -> Vec<RNA4> {
+        vec![*self]
+    }
+}
+
+impl Expand for RNA {
+    type Concrete = RNA4;
+
+    /// Expands into the [`RNA4`] bases that the base matches.
+    ///
+    /// [`RNA4`]: ../alphabet/enum.RNA4.html
+    fn expand(&self) -> Vec<RNA4> {
+        let matches = self.redundant_matches();
+        if matches.is_empty() {
+            vec![RNA4::try_from(self).expect("Non-redundant RNA is RNA4.")]
+        } else {
+            matches
+                .iter()
+                .map(|b| RNA4::try_from(b).expect("Non-redundant RNA is RNA4."))
+                .collect()
+        }
+    }
+}
+
+impl Expand for AA {
+    type Concrete = AA;
+
+    /// Expands `B`, `J`, `Z` and `X` into the residues they represent.
+    fn expand(&self) -> Vec<AA> {
+        let matches = self.redundant_matches();
+        if matches.is_empty() {
+            vec![*self]
+        } else {
+            matches
+        }
+    }
+}
+
+/// How to handle windows containing redundant letters.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Redundancy {
+    /// Skip windows containing any redundant letters.
+    Skip,
+    /// Yield every concrete kmer that a window represents.
+    Expand,
+    /// Expand windows that represent at most this many concrete kmers,
+    /// and skip the rest.
+    Cap(usize),
+}
+
+impl Redundancy {
+    /// The largest number of kmers that a window may be expanded into.
+    fn limit(&self) -> usize {
+        match *self {
+            Redundancy::Skip => 1,
+            Redundancy::Expand => usize::max_value(),
+            Redundancy::Cap(n) => n,
+        }
+    }
+}
+
+/// Yields an iterator over the concrete kmers in a sequence that may contain
+/// redundant letters.
+///
+/// This trait is automatically implemented for any iterator over an
+/// alphabet implementing [`Expand`] (owned or borrowed).
+/// Because several alphabets can expand to the same concrete alphabet, the
+/// alphabet of the sequence is given along with the kmer size, e.g.
+/// `expanded_kmers::<DNA, U5>(..)`.
+///
+/// [`Expand`]: trait.Expand.html
+pub trait IntoExpandedKmers: Iterator + Sized {
+    fn expanded_kmers<A, K>(self, redundancy: Redundancy) -> ExpandedKmers<Self, A, K>
+    where
+        Self::Item: Borrow<A>,
+        A: Expand,
+        K: Unsigned;
+}
+
+impl<I: Iterator> IntoExpandedKmers for I {
+    fn expanded_kmers<A, K>(self, redundancy: Redundancy) -> ExpandedKmers<Self, A, K>
+    where
+        I::Item: Borrow<A>,
+        A: Expand,
+        K: Unsigned,
+    {
+        ExpandedKmers {
+            iter: self,
+            redundancy: redundancy,
+            window: VecDeque::with_capacity(K::to_usize()),
+            position: 0,
+            pending: VecDeque::new(),
+            alphabet: PhantomData,
+        }
+    }
+}
+
+/// An iterator adapter over the concrete kmers in a sequence that may
+/// contain redundant letters.
+///
+/// Yields tuples of the window position and a concrete kmer. Windows that
+/// are expanded yield their kmers in rank order.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::DNA::*;
+/// use seqrs::alphabet::{DNA, DNA4};
+/// use seqrs::kmer::{IntoExpandedKmers, Kmer, Redundancy, SimpleKmer};
+/// use typenum::U2;
+///
+/// let seq = vec![A, R, T];
+/// let kmers: Vec<(usize, SimpleKmer<DNA4, U2>)> = seq
+///     .iter()
+///     .expanded_kmers::<DNA, U2>(Redundancy::Expand)
+///     .collect();
+///
+/// let bases: Vec<(usize, Vec<DNA4>)> = kmers.iter().map(|(i, k)| (*i, k.bases())).collect();
+/// assert_eq!(bases, vec![
+///     (0, vec![DNA4::A, DNA4::A]),
+///     (0, vec![DNA4::A, DNA4::G]),
+///     (1, vec![DNA4::A, DNA4::T]),
+///     (1, vec![DNA4::G, DNA4::T]),
+/// ]);
+///
+/// let skipped = seq.iter().expanded_kmers::<DNA, U2>(Redundancy::Skip);
+/// assert_eq!(skipped.count(), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExpandedKmers<I, A, K>
+where
+    A: Expand,
+{
+    iter: I,
+    redundancy: Redundancy,
+    // The ranks of the concrete letters for each base in the window.
+    window: VecDeque<Vec<usize>>,
+    position: usize,
+    pending: VecDeque<(usize, SimpleKmer<A::Concrete, K>)>,
+    alphabet: PhantomData<A>,
+}
+
+impl<I, A, K> ExpandedKmers<I, A, K>
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Expand,
+    K: Unsigned,
+{
+    /// Move the window along by one base, returning false when the
+    /// sequence is exhausted.
+    fn advance(&mut self) -> bool {
+        if self.window.len() == K::to_usize() {
+            self.window.pop_front();
+            self.position += 1;
+        }
+
+        while self.window.len() < K::to_usize() {
+            match self.iter.next() {
+                Some(base) => {
+                    let ranks = base.borrow().expand().iter().map(|b| b.rank()).collect();
+                    self.window.push_back(ranks);
+                }
+                None => return false,
+            }
+        }
+
+        true
+    }
+
+    /// Queue every concrete kmer in the current window, if there are few
+    /// enough of them.
+    fn expand_window(&mut self) {
+        let n = self
+            .window
+            .iter()
+            .fold(1_usize, |acc, b| acc.saturating_mul(b.len()));
+
+        if n == 0 || n > self.redundancy.limit() {
+            return;
+        }
+
+        let card = A::Concrete::cardinality();
+
+        // Count through the combinations like an odometer, so that the
+        // kmers come out in rank order.
+        let mut indices = vec![0; self.window.len()];
+        for _ in 0..n {
+            let inner = self
+                .window
+                .iter()
+                .zip(indices.iter())
+                .fold(0, |acc, (b, &i)| acc * card + b[i]);
+
+            self.pending
+                .push_back((self.position, SimpleKmer::from_inner(inner)));
+
+            for (b, i) in self.window.iter().zip(indices.iter_mut()).rev() {
+                *i += 1;
+                if *i < b.len() {
+                    break;
+                }
+                *i = 0;
+            }
+        }
+    }
+}
+
+impl<I, A, K> Iterator for ExpandedKmers<I, A, K>
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Expand,
+    K: Unsigned,
+{
+    type Item = (usize, SimpleKmer<A::Concrete, K>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(kmer) = self.pending.pop_front() {
+                return Some(kmer);
+            }
+
+            if !self.advance() {
+                return None;
+            }
+
+            self.expand_window();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kmer::{IntoKmers, Kmer};
+    use crate::matcher::Match;
+    use proptest::collection::vec;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+    use typenum::{U2, U3};
+
+    #[test]
+    fn test_expand() {
+        assert_eq!(DNA::N.expand(), DNA4::variants());
+        assert_eq!(DNA::Y.expand(), vec![DNA4::C, DNA4::T]);
+        assert_eq!(DNA::G.expand(), vec![DNA4::G]);
+        assert_eq!(RNA::K.expand(), vec![RNA4::G, RNA4::U]);
+        assert_eq!(AA::B.expand(), vec![AA::D, AA::N]);
+        assert_eq!(AA::W.expand(), vec![AA::W]);
+    }
+
+    #[test]
+    fn test_cap() {
+        let seq = vec![DNA::A, DNA::N, DNA::R, DNA::A];
+        let kmers: Vec<(usize, SimpleKmer<DNA4, U2>)> = seq
+            .iter()
+            .expanded_kmers::<DNA, U2>(Redundancy::Cap(4))
+            .collect();
+
+        // NR represents 8 kmers, so it's skipped.
+        let positions: Vec<usize> = kmers.iter().map(|(p, _)| *p).collect();
+        assert_eq!(positions, vec![0, 0, 0, 0, 2, 2]);
+    }
+
+    #[test]
+    fn test_aa() {
+        let seq = vec![AA::M, AA::J, AA::K];
+        let kmers: Vec<(usize, SimpleKmer<AA, U3>)> = seq
+            .iter()
+            .expanded_kmers::<AA, U3>(Redundancy::Expand)
+            .collect();
+
+        let bases: Vec<Vec<AA>> = kmers.iter().map(|(_, k)| k.bases()).collect();
+        assert_eq!(
+            bases,
+            vec![vec![AA::M, AA::I, AA::K], vec![AA::M, AA::L, AA::K]]
+        );
+    }
+
+    proptest! {
+        // Without redundant bases every policy yields the ordinary kmers.
+        #[test]
+        fn test_concrete_matches_kmers(seq in vec(select(DNA4::variants()), 0..50)) {
+            let dna: Vec<DNA> = seq.iter().map(DNA::from).collect();
+            let expected: Vec<SimpleKmer<DNA4, U3>> = seq.iter().kmers::<U3>().collect();
+
+            for &redundancy in &[Redundancy::Skip, Redundancy::Expand, Redundancy::Cap(2)] {
+                let kmers: Vec<SimpleKmer<DNA4, U3>> = dna
+                    .iter()
+                    .expanded_kmers::<DNA, U3>(redundancy)
+                    .map(|(_, k)| k)
+                    .collect();
+                assert_eq!(&kmers, &expected);
+            }
+        }
+
+        // Every expanded kmer matches its window, and windows are expanded
+        // into the expected number of kmers.
+        #[test]
+        fn test_expanded_kmers_match_window(seq in vec(select(DNA::variants()), 0..30)) {
+            let kmers: Vec<(usize, SimpleKmer<DNA4, U3>)> = seq
+                .iter()
+                .expanded_kmers::<DNA, U3>(Redundancy::Expand)
+                .collect();
+
+            for (p, kmer) in &kmers {
+                for (base, concrete) in seq[*p..*p + 3].iter().zip(kmer.bases()) {
+                    assert!(base.matches(&DNA::from(concrete)));
+                }
+            }
+
+            let expected: usize = seq
+                .windows(3)
+                .map(|w| w.iter().map(|b| b.expand().len()).product::<usize>())
+                .sum();
+            assert_eq!(kmers.len(), expected);
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Orderings over kmer ranks used to select kmers from a sequence.
+
+/// Maps a kmer rank to the key used to order kmers during selection.
+///
+/// Selection schemes like minimizers pick the kmer with the smallest key.
+/// Using the rank directly favours kmers like `AAAA...` that sort first,
+/// so a hash is usually preferable.
+pub trait KmerHasher {
+    fn hash_rank(&self, rank: usize) -> u64;
+}
+
+/// Orders kmers by their rank, i.e. lexicographically by alphabet variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Lexicographic;
+
+impl KmerHasher for Lexicographic {
+    #[inline]
+    fn hash_rank(&self, rank: usize) -> u64 {
+        rank as u64
+    }
+}
+
+/// An invertible integer hash over the lowest `bits` bits of a rank.
+///
+/// This is Thomas Wang's 64-bit integer hash with each step masked, as used
+/// by minimap2. Because every step is invertible, distinct kmers always have
+/// distinct hashes and the original rank can be recovered with [`invert`].
+///
+/// [`invert`]: #method.invert
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::kmer::{InvertibleHash, KmerHasher};
+///
+/// // 2 bits per base for a DNA4 11-mer.
+/// let hasher = InvertibleHash::new(22);
+/// let h = hasher.hash_rank(12345);
+///
+/// assert!(h < (1 << 22));
+/// assert_eq!(hasher.invert(h), 12345);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvertibleHash {
+    mask: u64,
+}
+
+impl InvertibleHash {
+    /// Create a hash over the lowest `bits` bits (1 to 64) of a rank.
+    pub fn new(bits: u32) -> Self {
+        debug_assert!(bits > 0 && bits <= 64);
+        let mask = if bits >= 64 {
+            u64::max_value()
+        } else {
+            (1 << bits) - 1
+        };
+
+        InvertibleHash { mask: mask }
+    }
+
+    /// Hash an integer key.
+    #[inline]
+    pub fn hash(&self, key: u64) -> u64 {
+        let mask = self.mask;
+        let mut key = key & mask;
+        key = (!key).wrapping_add(key << 21) & mask;
+        key ^= key >> 24;
+        key = key.wrapping_add(key << 3).wrapping_add(key << 8) & mask;
+        key ^= key >> 14;
+        key = key.wrapping_add(key << 2).wrapping_add(key << 4) & mask;
+        key ^= key >> 28;
+        key = key.wrapping_add(key << 31) & mask;
+        key
+    }
+
+    /// Recover the key that produced a hash.
+    pub fn invert(&self, hash: u64) -> u64 {
+        let mask = self.mask;
+        let mut key = hash & mask;
+
+        // Invert key = key + (key << 31)
+        let tmp = key.wrapping_sub(key << 31);
+        key = key.wrapping_sub(tmp << 31) & mask;
+
+        // Invert key = key ^ (key >> 28)
+        let tmp = key ^ key >> 28;
+        key ^= tmp >> 28;
+
+        // Invert key *= 21
+        key = key.wrapping_mul(14_933_078_535_860_113_213) & mask;
+
+        // Invert key = key ^ (key >> 14)
+        let tmp = key ^ key >> 14;
+        let tmp = key ^ tmp >> 14;
+        let tmp = key ^ tmp >> 14;
+        key ^= tmp >> 14;
+
+        // Invert key *= 265
+        key = key.wrapping_mul(15_244_667_743_933_553_977) & mask;
+
+        // Invert key = key ^ (key >> 24)
+        let tmp = key ^ key >> 24;
+        key ^= tmp >> 24;
+
+        // Invert key = (!key) + (key << 21)
+        let tmp = !key;
+        let tmp = !(key.wrapping_sub(tmp << 21));
+        let tmp = !(key.wrapping_sub(tmp << 21));
+        key = !(key.wrapping_sub(tmp << 21)) & mask;
+        key
+    }
+}
+
+impl Default for InvertibleHash {
+    /// Hashes over all 64 bits.
+    fn default() -> Self {
+        InvertibleHash::new(64)
+    }
+}
+
+impl KmerHasher for InvertibleHash {
+    #[inline]
+    fn hash_rank(&self, rank: usize) -> u64 {
+        self.hash(rank as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::any;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_lexicographic() {
+        assert_eq!(Lexicographic.hash_rank(10), 10);
+    }
+
+    proptest! {
+        #[test]
+        fn test_invert_recovers_original(key in any::<u64>(), bits in 1_u32..65) {
+            let hasher = InvertibleHash::new(bits);
+            let masked = key & hasher.mask;
+            let hashed = hasher.hash(masked);
+            assert!(hashed & !hasher.mask == 0);
+            assert_eq!(hasher.invert(hashed), masked);
+        }
+    }
+}
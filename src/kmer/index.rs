@@ -0,0 +1,352 @@
+//! An index from kmers to the positions they occur at in a set of sequences.
+//!
+//! Kmers are indexed by their canonical form, so queries find matches on
+//! either strand of the indexed sequences.
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use typenum::Unsigned;
+
+use crate::alphabet::Alphabet;
+use crate::complement::Complement;
+use crate::kmer::{IntoCanonicalKmers, SimpleKmer, Strand};
+
+/// The location of a kmer in an indexed sequence.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct KmerPosition {
+    /// The index of the sequence, in the order that they were inserted.
+    pub seq_id: usize,
+    /// The offset of the first base of the kmer in the sequence.
+    pub offset: usize,
+    /// Whether the sequence contains the kmer itself or its reverse
+    /// complement.
+    pub strand: Strand,
+}
+
+/// A kmer shared by a query and an indexed sequence.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Hit {
+    /// The offset of the kmer in the query.
+    pub query_offset: usize,
+    /// The index of the matching sequence.
+    pub seq_id: usize,
+    /// The offset of the kmer in the matching sequence.
+    pub offset: usize,
+    /// `Reverse` if the query kmer matches the reverse complement of the
+    /// indexed sequence.
+    pub strand: Strand,
+}
+
+/// All hits between a query and one strand of an indexed sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HitList {
+    pub seq_id: usize,
+    pub strand: Strand,
+    /// Hits ordered by query offset.
+    pub hits: Vec<Hit>,
+}
+
+/// Maps kmers to the positions where they occur in a set of sequences.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::DNA4;
+/// use seqrs::alphabet::DNA4::*;
+/// use seqrs::kmer::{KmerIndex, Strand};
+/// use typenum::U3;
+///
+/// let refs = vec![vec![A, C, G, T, T], vec![G, G, A, A, C]];
+/// let index: KmerIndex<DNA4, U3> = refs.iter().collect();
+///
+/// // GTT is the reverse complement of AAC.
+/// let lists = index.hit_lists(&[G, T, T]);
+/// assert_eq!(lists.len(), 2);
+/// assert_eq!(lists[0].seq_id, 0);
+/// assert_eq!(lists[0].hits[0].offset, 2);
+/// assert_eq!(lists[1].seq_id, 1);
+/// assert_eq!(lists[1].strand, Strand::Reverse);
+/// assert_eq!(lists[1].hits[0].offset, 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KmerIndex<A, K> {
+    positions: HashMap<usize, Vec<KmerPosition>>,
+    n_seqs: usize,
+    kmer: PhantomData<SimpleKmer<A, K>>,
+}
+
+impl<A, K> KmerIndex<A, K>
+where
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+{
+    /// Create an empty index.
+    pub fn new() -> Self {
+        KmerIndex {
+            positions: HashMap::new(),
+            n_seqs: 0,
+            kmer: PhantomData,
+        }
+    }
+
+    /// The number of sequences that have been inserted.
+    pub fn n_seqs(&self) -> usize {
+        self.n_seqs
+    }
+
+    /// The number of distinct canonical kmers in the index.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns true if the index contains no kmers.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Add the kmers in a sequence to the index, returning the id of the
+    /// sequence.
+    pub fn insert<I>(&mut self, seq: I) -> usize
+    where
+        I: IntoIterator,
+        I::Item: Borrow<A>,
+    {
+        let seq_id = self.n_seqs;
+        self.n_seqs += 1;
+
+        let mut kmers = IntoCanonicalKmers::<A>::canonical_kmers::<K>(seq.into_iter());
+        let mut offset = 0;
+
+        while let Some((fwd, rev)) = kmers.next_pair() {
+            let (canonical, strand) = orient(fwd, rev);
+            let position = KmerPosition {
+                seq_id: seq_id,
+                offset: offset,
+                strand: strand,
+            };
+
+            self.positions
+                .entry(canonical.rank())
+                .or_insert_with(Vec::new)
+                .push(position);
+            offset += 1;
+        }
+
+        seq_id
+    }
+
+    /// Find the positions of a kmer.
+    ///
+    /// Positions on the `Reverse` strand contain the reverse complement of
+    /// the kmer.
+    pub fn get(&self, kmer: &SimpleKmer<A, K>) -> Vec<KmerPosition> {
+        let (canonical, strand) = orient(*kmer, kmer.reverse_complement());
+        self.positions
+            .get(&canonical.rank())
+            .map(|p| {
+                p.iter()
+                    .map(|&pos| KmerPosition {
+                        strand: relative(strand, pos.strand),
+                        ..pos
+                    })
+                    .collect()
+            })
+            .unwrap_or_else(Vec::new)
+    }
+
+    /// Find every kmer shared between a query sequence and the index.
+    ///
+    /// Hits are ordered by query offset, then by the order of insertion.
+    pub fn query<I>(&self, seq: I) -> Vec<Hit>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<A>,
+    {
+        let mut kmers = IntoCanonicalKmers::<A>::canonical_kmers::<K>(seq.into_iter());
+        let mut query_offset = 0;
+        let mut hits = Vec::new();
+
+        while let Some((fwd, rev)) = kmers.next_pair() {
+            let (canonical, strand) = orient(fwd, rev);
+
+            if let Some(positions) = self.positions.get(&canonical.rank()) {
+                hits.extend(positions.iter().map(|pos| Hit {
+                    query_offset: query_offset,
+                    seq_id: pos.seq_id,
+                    offset: pos.offset,
+                    strand: relative(strand, pos.strand),
+                }));
+            }
+
+            query_offset += 1;
+        }
+
+        hits
+    }
+
+    /// Group the hits of a query by the sequence and strand they hit.
+    ///
+    /// Lists are ordered by decreasing number of hits, then by sequence id
+    /// and strand, so the best candidates for extension come first.
+    pub fn hit_lists<I>(&self, seq: I) -> Vec<HitList>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<A>,
+    {
+        let mut groups: HashMap<(usize, Strand), Vec<Hit>> = HashMap::new();
+        for hit in self.query(seq) {
+            groups
+                .entry((hit.seq_id, hit.strand))
+                .or_insert_with(Vec::new)
+                .push(hit);
+        }
+
+        let mut lists: Vec<HitList> = groups
+            .into_iter()
+            .map(|((seq_id, strand), hits)| HitList {
+                seq_id: seq_id,
+                strand: strand,
+                hits: hits,
+            })
+            .collect();
+
+        lists.sort_by(|a, b| {
+            b.hits
+                .len()
+                .cmp(&a.hits.len())
+                .then(a.seq_id.cmp(&b.seq_id))
+                .then(a.strand.cmp(&b.strand))
+        });
+        lists
+    }
+}
+
+impl<A, K> Default for KmerIndex<A, K>
+where
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Build an index from an iterator of sequences, using their order as ids.
+impl<A, K, S> FromIterator<S> for KmerIndex<A, K>
+where
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+    S: IntoIterator,
+    S::Item: Borrow<A>,
+{
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut index = Self::new();
+        for seq in iter {
+            index.insert(seq);
+        }
+        index
+    }
+}
+
+/// Pick the canonical kmer and the strand that it was read from.
+#[inline]
+fn orient<A, K>(fwd: SimpleKmer<A, K>, rev: SimpleKmer<A, K>) -> (SimpleKmer<A, K>, Strand) {
+    if rev < fwd {
+        (rev, Strand::Reverse)
+    } else {
+        (fwd, Strand::Forward)
+    }
+}
+
+/// The strand of one kmer relative to another, given their strands relative
+/// to the canonical kmer.
+#[inline]
+fn relative(a: Strand, b: Strand) -> Strand {
+    if a == b {
+        Strand::Forward
+    } else {
+        Strand::Reverse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::DNA4;
+    use crate::alphabet::DNA4::*;
+    use crate::complement::IntoReverseComplement;
+    use crate::kmer::Kmer;
+    use proptest::collection::vec;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+    use typenum::{U3, U4};
+
+    #[test]
+    fn test_get() {
+        let index: KmerIndex<DNA4, U3> = vec![vec![A, C, G, T, A, C, G]].iter().collect();
+        assert_eq!(index.n_seqs(), 1);
+
+        let acg = SimpleKmer::from_bases(&[A, C, G]).unwrap();
+        let offsets: Vec<(usize, Strand)> = index
+            .get(&acg)
+            .iter()
+            .map(|p| (p.offset, p.strand))
+            .collect();
+
+        // CGT is also the reverse complement of ACG.
+        assert_eq!(
+            offsets,
+            vec![
+                (0, Strand::Forward),
+                (1, Strand::Reverse),
+                (4, Strand::Forward)
+            ]
+        );
+
+        let cgt = acg.reverse_complement();
+        let strands: Vec<Strand> = index.get(&cgt).iter().map(|p| p.strand).collect();
+        assert_eq!(strands, vec![Strand::Reverse, Strand::Forward, Strand::Reverse]);
+
+        let aaa = SimpleKmer::from_bases(&[A, A, A]).unwrap();
+        assert!(index.get(&aaa).is_empty());
+    }
+
+    proptest! {
+        // Every hit should point at identical kmers (or reverse complements).
+        #[test]
+        fn test_query_hits_match(
+            refs in vec(vec(select(DNA4::variants()), 0..30), 1..4),
+            query in vec(select(DNA4::variants()), 0..30),
+        ) {
+            let index: KmerIndex<DNA4, U4> = refs.iter().collect();
+            let hits = index.query(&query);
+
+            for hit in &hits {
+                let q: Vec<DNA4> = query[hit.query_offset..hit.query_offset + 4].to_vec();
+                let r = &refs[hit.seq_id][hit.offset..hit.offset + 4];
+                let r: Vec<DNA4> = match hit.strand {
+                    Strand::Forward => r.to_vec(),
+                    Strand::Reverse => r.iter().reverse_complement().collect(),
+                };
+                assert_eq!(q, r);
+            }
+
+            // Querying with a reference finds every one of its own kmers.
+            let own = index.query(&refs[0]);
+            let n = refs[0].len().saturating_sub(3);
+            for i in 0..n {
+                assert!(own.contains(&Hit {
+                    query_offset: i,
+                    seq_id: 0,
+                    offset: i,
+                    strand: Strand::Forward,
+                }));
+            }
+
+            let lists = index.hit_lists(&query);
+            assert_eq!(lists.iter().map(|l| l.hits.len()).sum::<usize>(), hits.len());
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! `(w, k)` minimizers over nucleotide sequences.
+//!
+//! A minimizer is the kmer with the smallest key among `w` consecutive
+//! kmers. Every window of `w` kmers contains at least one minimizer, so they
+//! make a compact and strand independent sample of a sequence.
+
+use std::borrow::Borrow;
+use std::collections::VecDeque;
+use typenum::Unsigned;
+
+use crate::alphabet::Alphabet;
+use crate::complement::Complement;
+use crate::kmer::{CanonicalKmers, IntoCanonicalKmers, KmerHasher, SimpleKmer, Strand, StrandedKmer};
+
+/// Yields an iterator over the minimizers in another iterator.
+///
+/// This trait is automatically implemented for any iterator over an
+/// [`Alphabet`] that implements [`Complement`].
+///
+/// [`Alphabet`]: ../alphabet/trait.Alphabet.html
+/// [`Complement`]: ../complement/trait.Complement.html
+pub trait IntoMinimizers<A>: Sized {
+    fn minimizers<K: Unsigned, H: KmerHasher>(self, w: usize, hasher: H) -> Minimizers<Self, A, K, H>;
+}
+
+impl<I, A> IntoMinimizers<A> for I
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Alphabet + Complement<Compl = A>,
+{
+    fn minimizers<K: Unsigned, H: KmerHasher>(self, w: usize, hasher: H) -> Minimizers<Self, A, K, H> {
+        assert!(w > 0, "Minimizer window size must be at least 1.");
+        Minimizers {
+            kmers: self.canonical_kmers(),
+            hasher: hasher,
+            w: w,
+            position: 0,
+            window: VecDeque::with_capacity(w),
+            last: None,
+        }
+    }
+}
+
+/// An iterator adapter over the `(w, k)` minimizers in another iterator.
+///
+/// Each kmer is keyed by the smaller hash of its forward and reverse
+/// complement, and the strand of the selected orientation is reported.
+/// Ties are broken by taking the leftmost kmer, and a minimizer shared by
+/// consecutive windows is only yielded once.
+/// Sequences with fewer than `w` kmers have no minimizers.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::DNA4;
+/// use seqrs::alphabet::DNA4::*;
+/// use seqrs::kmer::{IntoMinimizers, Kmer, Lexicographic, Strand, StrandedKmer};
+/// use typenum::U3;
+///
+/// let seq = vec![T, T, T, G, C, A, T];
+/// let mins: Vec<StrandedKmer<DNA4, U3>> = seq
+///     .iter()
+///     .minimizers::<U3, _>(3, Lexicographic)
+///     .collect();
+///
+/// // TTT is AAA on the reverse strand.
+/// assert_eq!(mins[0].kmer.bases(), vec![A, A, A]);
+/// assert_eq!(mins[0].position, 0);
+/// assert_eq!(mins[0].strand, Strand::Reverse);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Minimizers<I, A, K, H> {
+    kmers: CanonicalKmers<I, A, K>,
+    hasher: H,
+    w: usize,
+    position: usize,
+    window: VecDeque<(u64, StrandedKmer<A, K>)>,
+    last: Option<usize>,
+}
+
+impl<I, A, K, H> Iterator for Minimizers<I, A, K, H>
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+    H: KmerHasher,
+{
+    type Item = StrandedKmer<A, K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (fwd, rev) = self.kmers.next_pair()?;
+            let position = self.position;
+            self.position += 1;
+
+            let candidate = select(&self.hasher, fwd, rev, position);
+
+            // Keep the window sorted by key, so the front is the minimum.
+            while let Some(&(key, _)) = self.window.back() {
+                if key > candidate.0 {
+                    self.window.pop_back();
+                } else {
+                    break;
+                }
+            }
+            self.window.push_back(candidate);
+
+            while let Some(&(_, ref kmer)) = self.window.front() {
+                if kmer.position + self.w <= position {
+                    self.window.pop_front();
+                } else {
+                    break;
+                }
+            }
+
+            if position + 1 < self.w {
+                continue;
+            }
+
+            if let Some(&(_, min)) = self.window.front() {
+                if self.last != Some(min.position) {
+                    self.last = Some(min.position);
+                    return Some(min);
+                }
+            }
+        }
+    }
+}
+
+/// Pick the orientation of a kmer with the smallest key.
+#[inline]
+pub(crate) fn select<A, K, H>(
+    hasher: &H,
+    fwd: SimpleKmer<A, K>,
+    rev: SimpleKmer<A, K>,
+    position: usize,
+) -> (u64, StrandedKmer<A, K>)
+where
+    A: Alphabet,
+    K: Unsigned,
+    H: KmerHasher,
+{
+    let fwd_key = hasher.hash_rank(fwd.rank());
+    let rev_key = hasher.hash_rank(rev.rank());
+
+    if rev_key < fwd_key {
+        (rev_key, StrandedKmer::new(rev, position, Strand::Reverse))
+    } else {
+        (fwd_key, StrandedKmer::new(fwd, position, Strand::Forward))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::DNA4;
+    use crate::alphabet::DNA4::*;
+    use crate::complement::IntoReverseComplement;
+    use crate::kmer::{IntoKmers, InvertibleHash, Kmer, Lexicographic};
+    use proptest::collection::vec;
+    use proptest::sample::select as choose;
+    use proptest::{proptest, proptest_helper};
+    use typenum::{U3, U5};
+
+    /// Find minimizers by brute force over every window.
+    fn naive<H: KmerHasher>(seq: &[DNA4], w: usize, hasher: &H) -> Vec<StrandedKmer<DNA4, U5>> {
+        let kmers: Vec<(u64, StrandedKmer<DNA4, U5>)> = seq
+            .iter()
+            .kmers::<U5>()
+            .enumerate()
+            .map(|(i, k)| select(hasher, k, k.reverse_complement(), i))
+            .collect();
+
+        let mut output: Vec<StrandedKmer<DNA4, U5>> = Vec::new();
+        for window in kmers.windows(w) {
+            let mut min = window[0];
+            for candidate in window.iter().skip(1) {
+                if candidate.0 < min.0 {
+                    min = *candidate;
+                }
+            }
+
+            if output.last().map(|k| k.position) != Some(min.1.position) {
+                output.push(min.1);
+            }
+        }
+        output
+    }
+
+    #[test]
+    fn test_minimizers_positions() {
+        let seq = vec![G, G, A, C, G, T, T, C, C];
+        let mins: Vec<StrandedKmer<DNA4, U3>> =
+            seq.iter().minimizers::<U3, _>(2, Lexicographic).collect();
+        let positions: Vec<usize> = mins.iter().map(|m| m.position).collect();
+
+        // GGA/TCC, GAC/GTC, ACG/CGT, CGT/ACG, GTT/AAC, TTC/GAA, TCC/GGA
+        // The tied ACG at positions 2 and 3 is only reported once.
+        assert_eq!(positions, vec![1, 2, 4, 5]);
+        assert_eq!(mins[1].strand, Strand::Forward);
+        assert_eq!(mins[2].strand, Strand::Reverse);
+        assert_eq!(mins[2].kmer.bases(), vec![A, A, C]);
+    }
+
+    #[test]
+    fn test_too_short() {
+        let seq = vec![A, C, G, T];
+        let mins: Vec<StrandedKmer<DNA4, U3>> =
+            seq.iter().minimizers::<U3, _>(3, Lexicographic).collect();
+        assert!(mins.is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn test_minimizers_match_naive(
+            seq in vec(choose(DNA4::variants()), 0..100),
+            w in 1_usize..10,
+        ) {
+            let hasher = InvertibleHash::new(10);
+            let mins: Vec<StrandedKmer<DNA4, U5>> = seq
+                .iter()
+                .minimizers::<U5, _>(w, hasher)
+                .collect();
+            assert_eq!(mins, naive(&seq, w, &hasher));
+        }
+
+        // The same kmers are selected from either strand.
+        #[test]
+        fn test_minimizers_strand_independent(
+            seq in vec(choose(DNA4::variants()), 0..100),
+        ) {
+            let hasher = InvertibleHash::new(10);
+            let mut fwd: Vec<SimpleKmer<DNA4, U5>> = seq
+                .iter()
+                .minimizers::<U5, _>(4, hasher)
+                .map(|m| m.kmer)
+                .collect();
+
+            let mut rev: Vec<SimpleKmer<DNA4, U5>> = seq
+                .iter()
+                .reverse_complement()
+                .minimizers::<U5, _>(4, hasher)
+                .map(|m| m.kmer)
+                .collect();
+
+            // Tied kmers may be reported a different number of times.
+            fwd.sort();
+            fwd.dedup();
+            rev.sort();
+            rev.dedup();
+            assert_eq!(fwd, rev);
+        }
+    }
+}
@@ -0,0 +1,846 @@
+//! Fixed length kmers packed into a single integer, and a rolling iterator
+//! adapter over sequences.
+//!
+//! Kmers are represented by their rank, using the same positional arithmetic
+//! as [`Codon`]. The rank of each base is multiplied by the cardinality of
+//! the alphabet raised to the power of its distance from the end of the kmer.
+//! This means that adding a base to the end of a kmer and dropping the first
+//! one only requires a couple of integer operations, so iterating over all
+//! kmers in a sequence is O(1) per base rather than O(k).
+//!
+//! The kmer size is encoded in the type using [`typenum`] unsigned integers.
+//!
+//! Subsets of kmers can be sampled with the [`minimizers`] and [`syncmers`]
+//! iterator adapters, which order kmers using a [`KmerHasher`], and
+//! [`spaced_kmers`] builds kmers from the positions selected by a
+//! [`SpacedSeed`].
+//!
+//! # Examples:
+//!
+//! ```
+//! use seqrs::alphabet::DNA4;
+//! use seqrs::alphabet::DNA4::*;
+//! use seqrs::alphabet::Alphabet;
+//! use seqrs::kmer::{IntoKmers, Kmer, SimpleKmer};
+//! use typenum::U3;
+//!
+//! let seq = vec![A, C, G, T, A];
+//! let kmers: Vec<SimpleKmer<DNA4, U3>> = seq.iter().kmers::<U3>().collect();
+//!
+//! assert_eq!(kmers.len(), 3);
+//! assert_eq!(kmers[0].bases(), vec![A, C, G]);
+//! assert_eq!(kmers[2].bases(), vec![G, T, A]);
+//!
+//! // Kmers are alphabets in their own right, so they have a rank.
+//! assert_eq!(kmers[0].rank(), 0b00_01_10);
+//! ```
+//!
+//! [`Codon`]: ../codon/struct.Codon.html
+//! [`typenum`]: https://docs.rs/typenum
+//! [`minimizers`]: trait.IntoMinimizers.html
+//! [`syncmers`]: trait.IntoSyncmers.html
+//! [`KmerHasher`]: trait.KmerHasher.html
+//! [`spaced_kmers`]: trait.IntoSpacedKmers.html
+//! [`SpacedSeed`]: struct.SpacedSeed.html
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::marker::PhantomData;
+use typenum::Unsigned;
+
+use crate::alphabet::Alphabet;
+use crate::complement::Complement;
+
+mod bloom;
+mod counts;
+mod expand;
+mod hash;
+mod index;
+mod minimizer;
+mod spaced;
+mod syncmer;
+
+pub use self::bloom::{BloomFilter, CountingBloomFilter};
+pub use self::counts::{KmerCounts, DENSE_LIMIT};
+pub use self::expand::{Expand, ExpandedKmers, IntoExpandedKmers, Redundancy};
+pub use self::hash::{InvertibleHash, KmerHasher, Lexicographic};
+pub use self::index::{Hit, HitList, KmerIndex, KmerPosition};
+pub use self::minimizer::{IntoMinimizers, Minimizers};
+pub use self::spaced::{IntoSpacedKmers, MultiSpacedKmers, SpacedKmers, SpacedSeed};
+pub use self::syncmer::{IntoSyncmers, SyncmerKind, Syncmers};
+
+// See status of generic consts.
+// https://github.com/rust-lang/rust/pull/53645
+
+/// A sequence of exactly `k` bases from some alphabet.
+///
+/// Kmers are themselves alphabets, with a cardinality of the base alphabet
+/// cardinality to the power of `k`.
+pub trait Kmer: Alphabet {
+    /// The alphabet that the kmer is composed of.
+    type Base: Alphabet;
+
+    /// The number of bases in the kmer.
+    fn k() -> usize;
+
+    /// Get the base at position `index`, returning `None` if the index is
+    /// out of bounds.
+    fn get(&self, index: usize) -> Option<Self::Base>;
+
+    /// Construct a kmer from the first `k` elements of an iterator,
+    /// returning `None` if the iterator is too short.
+    fn from_bases<I>(bases: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Base>;
+
+    /// Returns a Vec of the bases in the kmer.
+    fn bases(&self) -> Vec<Self::Base> {
+        (0..Self::k()).filter_map(|i| self.get(i)).collect()
+    }
+}
+
+/// Kmers that can be updated in place as a window slides along a sequence.
+pub trait RollingKmer: Kmer {
+    /// Append a base to the end of the kmer, dropping the first base.
+    fn push_back(&mut self, base: &Self::Base);
+
+    /// Prepend a base to the start of the kmer, dropping the last base.
+    fn push_front(&mut self, base: &Self::Base);
+}
+
+/// A kmer stored as its rank in a single `usize`.
+///
+/// The largest `k` that can be stored depends on the alphabet, e.g. 32 for
+/// [`DNA4`] and 13 for [`AA`] on 64-bit platforms. Building larger kmers
+/// panics.
+///
+/// [`DNA4`]: ../alphabet/enum.DNA4.html
+/// [`AA`]: ../alphabet/enum.AA.html
+#[derive(Debug)]
+pub struct SimpleKmer<A, K> {
+    inner: usize,
+    size: PhantomData<K>,
+    alphabet: PhantomData<A>,
+}
+
+// The marker types don't need to implement these traits, so they're
+// implemented manually rather than derived.
+impl<A, K> Clone for SimpleKmer<A, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A, K> Copy for SimpleKmer<A, K> {}
+
+impl<A, K> PartialEq for SimpleKmer<A, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<A, K> Eq for SimpleKmer<A, K> {}
+
+impl<A, K> PartialOrd for SimpleKmer<A, K> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A, K> Ord for SimpleKmer<A, K> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<A, K> std::hash::Hash for SimpleKmer<A, K> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.inner.hash(state)
+    }
+}
+
+impl<A, K> SimpleKmer<A, K>
+where
+    A: Alphabet,
+    K: Unsigned,
+{
+    /// The rank "value" of the first base in the kmer.
+    /// Subtracting multiples of this removes the first base.
+    #[inline]
+    fn place() -> usize {
+        debug_assert!(K::to_usize() > 0);
+        Self::assert_fits();
+        A::cardinality().pow(K::to_u32() - 1)
+    }
+
+    /// Panics if the largest rank of a kmer doesn't fit in a `usize`.
+    #[inline]
+    fn assert_fits() {
+        let max_rank = A::cardinality()
+            .checked_pow(K::to_u32() - 1)
+            .and_then(|p| p.checked_mul(A::cardinality() - 1)?.checked_add(p - 1));
+        assert!(
+            max_rank.is_some(),
+            "Kmers of length {} are too long to be stored in a usize.",
+            K::to_usize()
+        );
+    }
+
+    /// The number of possible kmers, or `None` if it doesn't fit in a
+    /// `usize`, e.g. for `DNA4` 32-mers.
+    #[inline]
+    fn checked_cardinality() -> Option<usize> {
+        A::cardinality().checked_pow(K::to_u32())
+    }
+
+    #[inline]
+    fn from_inner(inner: usize) -> Self {
+        SimpleKmer {
+            inner: inner,
+            size: PhantomData,
+            alphabet: PhantomData,
+        }
+    }
+}
+
+impl<A, K> Alphabet for SimpleKmer<A, K>
+where
+    A: Alphabet,
+    K: Unsigned,
+{
+    /// The number of possible kmers given the alphabet.
+    ///
+    /// Saturates at `usize::max_value()` if every rank fits in a `usize` but
+    /// their count doesn't.
+    fn cardinality() -> usize {
+        Self::checked_cardinality().unwrap_or(usize::max_value())
+    }
+
+    fn rank(&self) -> usize {
+        self.inner
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(Self::checked_cardinality().map_or(true, |c| r < c));
+        Self::from_inner(r)
+    }
+
+    fn from_rank(r: usize) -> Option<Self> {
+        Self::assert_fits();
+        match Self::checked_cardinality() {
+            Some(c) if r >= c => None,
+            _ => Some(Self::from_inner(r)),
+        }
+    }
+
+    fn variants() -> Vec<Self> {
+        (0..Self::cardinality()).map(Self::from_inner).collect()
+    }
+}
+
+impl<A, K> Kmer for SimpleKmer<A, K>
+where
+    A: Alphabet,
+    K: Unsigned,
+{
+    type Base = A;
+
+    fn k() -> usize {
+        K::to_usize()
+    }
+
+    fn get(&self, index: usize) -> Option<A> {
+        if index >= Self::k() {
+            return None;
+        }
+
+        let place = A::cardinality().pow((Self::k() - index - 1) as u32);
+        A::from_rank((self.inner / place) % A::cardinality())
+    }
+
+    fn from_bases<I>(bases: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<A>,
+    {
+        Self::assert_fits();
+        let mut inner = 0;
+        let mut n = 0;
+
+        for base in bases.into_iter().take(Self::k()) {
+            inner = inner * A::cardinality() + base.borrow().rank();
+            n += 1;
+        }
+
+        if n == Self::k() {
+            Some(Self::from_inner(inner))
+        } else {
+            None
+        }
+    }
+}
+
+impl<A, K> RollingKmer for SimpleKmer<A, K>
+where
+    A: Alphabet,
+    K: Unsigned,
+{
+    #[inline]
+    fn push_back(&mut self, base: &A) {
+        self.inner = (self.inner % Self::place()) * A::cardinality() + base.rank();
+    }
+
+    #[inline]
+    fn push_front(&mut self, base: &A) {
+        self.inner = self.inner / A::cardinality() + base.rank() * Self::place();
+    }
+}
+
+impl<A, K> SimpleKmer<A, K>
+where
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+{
+    /// The reverse complement of the kmer.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::alphabet::DNA4;
+    /// use seqrs::alphabet::DNA4::*;
+    /// use seqrs::kmer::{Kmer, SimpleKmer};
+    /// use typenum::U4;
+    ///
+    /// let kmer = SimpleKmer::<DNA4, U4>::from_bases(&[A, A, C, G]).unwrap();
+    /// assert_eq!(kmer.reverse_complement().bases(), vec![C, G, T, T]);
+    /// ```
+    pub fn reverse_complement(&self) -> Self {
+        let mut inner = 0;
+        let mut rank = self.inner;
+
+        // Pop bases off the end of the kmer and push their complements on.
+        for _ in 0..K::to_usize() {
+            let base = unsafe { A::from_rank_unsafe(rank % A::cardinality()) };
+            inner = inner * A::cardinality() + base.complement().rank();
+            rank /= A::cardinality();
+        }
+
+        Self::from_inner(inner)
+    }
+
+    /// The canonical kmer, which is the smaller of the kmer and its reverse
+    /// complement.
+    ///
+    /// Canonical kmers are identical for both strands of a nucleic acid.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::alphabet::DNA4;
+    /// use seqrs::alphabet::DNA4::*;
+    /// use seqrs::kmer::{Kmer, SimpleKmer};
+    /// use typenum::U3;
+    ///
+    /// let kmer = SimpleKmer::<DNA4, U3>::from_bases(&[T, T, G]).unwrap();
+    /// assert_eq!(kmer.canonical().bases(), vec![C, A, A]);
+    /// assert_eq!(kmer.canonical(), kmer.reverse_complement().canonical());
+    /// ```
+    pub fn canonical(&self) -> Self {
+        std::cmp::min(*self, self.reverse_complement())
+    }
+}
+
+impl<A, K> Complement for SimpleKmer<A, K>
+where
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+{
+    type Compl = SimpleKmer<A, K>;
+
+    /// Complements each base in the kmer without reversing the order.
+    /// See [`reverse_complement`] for the other strand.
+    ///
+    /// [`reverse_complement`]: #method.reverse_complement
+    fn complement(&self) -> Self::Compl {
+        let mut inner = 0;
+
+        for base in self.bases() {
+            inner = inner * A::cardinality() + base.complement().rank();
+        }
+
+        Self::from_inner(inner)
+    }
+}
+
+impl<A, K> fmt::Display for SimpleKmer<A, K>
+where
+    A: Alphabet + Into<char>,
+    K: Unsigned,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for base in self.bases() {
+            write!(f, "{}", base.into())?;
+        }
+        Ok(())
+    }
+}
+
+/// The strand of a nucleic acid that a kmer was read from.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Strand {
+    Forward,
+    Reverse,
+}
+
+/// A kmer selected from a sequence, with the position of its first base and
+/// the strand that the kmer was read from.
+///
+/// If the strand is [`Reverse`], `kmer` is the reverse complement of the
+/// bases at `position` in the forward sequence.
+///
+/// [`Reverse`]: enum.Strand.html#variant.Reverse
+#[derive(Debug)]
+pub struct StrandedKmer<A, K> {
+    pub kmer: SimpleKmer<A, K>,
+    pub position: usize,
+    pub strand: Strand,
+}
+
+impl<A, K> StrandedKmer<A, K> {
+    /// Constructor function.
+    pub fn new(kmer: SimpleKmer<A, K>, position: usize, strand: Strand) -> Self {
+        StrandedKmer {
+            kmer: kmer,
+            position: position,
+            strand: strand,
+        }
+    }
+}
+
+impl<A, K> Clone for StrandedKmer<A, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A, K> Copy for StrandedKmer<A, K> {}
+
+impl<A, K> PartialEq for StrandedKmer<A, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.kmer == other.kmer && self.position == other.position && self.strand == other.strand
+    }
+}
+
+impl<A, K> Eq for StrandedKmer<A, K> {}
+
+/// Yields an iterator over all overlapping kmers in another iterator.
+///
+/// This trait is automatically implemented for any iterator over an
+/// [`Alphabet`] (owned or borrowed).
+///
+/// [`Alphabet`]: ../alphabet/trait.Alphabet.html
+pub trait IntoKmers<A>: Sized {
+    fn kmers<K: Unsigned>(self) -> Kmers<Self, A, K>;
+}
+
+impl<I, A> IntoKmers<A> for I
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Alphabet,
+{
+    fn kmers<K: Unsigned>(self) -> Kmers<Self, A, K> {
+        Kmers {
+            iter: self,
+            kmer: None,
+        }
+    }
+}
+
+/// An iterator adapter over the overlapping kmers in another iterator.
+///
+/// The first kmer is built from the first `k` bases, after which each new
+/// base is rolled onto the end of the previous kmer.
+#[derive(Debug, Clone)]
+pub struct Kmers<I, A, K> {
+    iter: I,
+    kmer: Option<SimpleKmer<A, K>>,
+}
+
+impl<I, A, K> Iterator for Kmers<I, A, K>
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Alphabet,
+    K: Unsigned,
+{
+    type Item = SimpleKmer<A, K>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.kmer {
+            Some(ref mut kmer) => {
+                let base = self.iter.next()?;
+                kmer.push_back(base.borrow());
+                Some(*kmer)
+            }
+            None => {
+                let kmer = SimpleKmer::from_bases(self.iter.by_ref())?;
+                self.kmer = Some(kmer);
+                Some(kmer)
+            }
+        }
+    }
+
+    /// Calculate the number of kmers remaining.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::alphabet::DNA4;
+    /// use seqrs::alphabet::DNA4::*;
+    /// use seqrs::kmer::IntoKmers;
+    /// use typenum::U3;
+    ///
+    /// let kmers = IntoKmers::<DNA4>::kmers::<U3>(vec![A, C].into_iter());
+    /// assert_eq!((0, Some(0)), kmers.size_hint());
+    ///
+    /// let kmers = IntoKmers::<DNA4>::kmers::<U3>(vec![A, C, G, T].into_iter());
+    /// assert_eq!((2, Some(2)), kmers.size_hint());
+    /// ```
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let adjust = |n: usize| match self.kmer {
+            Some(_) => n,
+            None => (n + 1).saturating_sub(K::to_usize()),
+        };
+
+        (adjust(lower), upper.map(adjust))
+    }
+}
+
+impl<I, A, K> ExactSizeIterator for Kmers<I, A, K>
+where
+    I: ExactSizeIterator,
+    I::Item: Borrow<A>,
+    A: Alphabet,
+    K: Unsigned,
+{
+}
+
+/// Yields an iterator over the canonical kmers in another iterator.
+///
+/// This trait is automatically implemented for any iterator over an
+/// [`Alphabet`] that implements [`Complement`].
+///
+/// [`Alphabet`]: ../alphabet/trait.Alphabet.html
+/// [`Complement`]: ../complement/trait.Complement.html
+pub trait IntoCanonicalKmers<A>: Sized {
+    fn canonical_kmers<K: Unsigned>(self) -> CanonicalKmers<Self, A, K>;
+}
+
+impl<I, A> IntoCanonicalKmers<A> for I
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Alphabet + Complement<Compl = A>,
+{
+    fn canonical_kmers<K: Unsigned>(self) -> CanonicalKmers<Self, A, K> {
+        CanonicalKmers {
+            iter: self,
+            pair: KmerPair::new(),
+        }
+    }
+}
+
+/// An iterator adapter over the canonical kmers in another iterator.
+///
+/// Both the forward kmer and its reverse complement are rolled along with
+/// each new base, so the reverse complement is never rebuilt from scratch.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::DNA4;
+/// use seqrs::alphabet::DNA4::*;
+/// use seqrs::complement::IntoReverseComplement;
+/// use seqrs::kmer::{IntoCanonicalKmers, SimpleKmer};
+/// use typenum::U3;
+///
+/// let seq = vec![A, C, G, T, T];
+/// let fwd: Vec<SimpleKmer<DNA4, U3>> = seq.iter().canonical_kmers::<U3>().collect();
+///
+/// // The canonical kmers of the other strand are the same, but reversed.
+/// let mut rev: Vec<SimpleKmer<DNA4, U3>> = seq
+///     .iter()
+///     .reverse_complement()
+///     .canonical_kmers::<U3>()
+///     .collect();
+/// rev.reverse();
+///
+/// assert_eq!(fwd, rev);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CanonicalKmers<I, A, K> {
+    iter: I,
+    pair: KmerPair<A, K>,
+}
+
+impl<I, A, K> CanonicalKmers<I, A, K>
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+{
+    /// Roll the next forward and reverse complement kmers.
+    #[inline]
+    pub(crate) fn next_pair(&mut self) -> Option<(SimpleKmer<A, K>, SimpleKmer<A, K>)> {
+        loop {
+            let base = self.iter.next()?;
+            if self.pair.push(base.borrow()) {
+                return Some((self.pair.fwd, self.pair.rev));
+            }
+        }
+    }
+}
+
+impl<I, A, K> Iterator for CanonicalKmers<I, A, K>
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+{
+    type Item = SimpleKmer<A, K>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (fwd, rev) = self.next_pair()?;
+        Some(std::cmp::min(fwd, rev))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let filled = self.pair.filled;
+        let adjust = |n: usize| {
+            if filled == K::to_usize() {
+                n
+            } else {
+                (n + filled + 1).saturating_sub(K::to_usize())
+            }
+        };
+
+        (adjust(lower), upper.map(adjust))
+    }
+}
+
+impl<I, A, K> ExactSizeIterator for CanonicalKmers<I, A, K>
+where
+    I: ExactSizeIterator,
+    I::Item: Borrow<A>,
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+{
+}
+
+/// A forward kmer and its reverse complement, rolled one base at a time.
+///
+/// Both kmers start as the all-zero rank, so they are only valid once `k`
+/// bases have been pushed.
+#[derive(Debug)]
+pub(crate) struct KmerPair<A, K> {
+    pub(crate) fwd: SimpleKmer<A, K>,
+    pub(crate) rev: SimpleKmer<A, K>,
+    pub(crate) filled: usize,
+}
+
+impl<A, K> Clone for KmerPair<A, K> {
+    fn clone(&self) -> Self {
+        KmerPair {
+            fwd: self.fwd,
+            rev: self.rev,
+            filled: self.filled,
+        }
+    }
+}
+
+impl<A, K> KmerPair<A, K>
+where
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+{
+    pub(crate) fn new() -> Self {
+        KmerPair {
+            fwd: SimpleKmer::from_inner(0),
+            rev: SimpleKmer::from_inner(0),
+            filled: 0,
+        }
+    }
+
+    /// Push a new base onto the pair, returning true if the kmers are full.
+    #[inline]
+    pub(crate) fn push(&mut self, base: &A) -> bool {
+        self.fwd.push_back(base);
+        self.rev.push_front(&base.complement());
+
+        if self.filled < K::to_usize() {
+            self.filled += 1;
+        }
+
+        self.filled == K::to_usize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::DNA4::*;
+    use crate::alphabet::{AA, DNA, DNA4};
+    use proptest::collection::vec;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+    use typenum::{U1, U14, U3, U32, U33, U4};
+
+    #[test]
+    fn test_cardinality() {
+        assert_eq!(SimpleKmer::<DNA4, U3>::cardinality(), 64);
+        assert_eq!(SimpleKmer::<DNA, U3>::cardinality(), 3375);
+        assert_eq!(SimpleKmer::<AA, U1>::cardinality(), 26);
+        assert_eq!(SimpleKmer::<DNA4, U32>::cardinality(), usize::max_value());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dna4_kmer_too_long() {
+        let _ = SimpleKmer::<DNA4, U33>::from_bases(vec![A; 33]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_aa_kmer_too_long() {
+        let _ = SimpleKmer::<AA, U14>::from_rank(0);
+    }
+
+    #[test]
+    fn test_from_bases() {
+        let kmer = SimpleKmer::<DNA4, U3>::from_bases(vec![A, C, G]).unwrap();
+        assert_eq!(kmer.rank(), 0b00_01_10);
+        assert_eq!(kmer.bases(), vec![A, C, G]);
+        assert_eq!(kmer.get(1), Some(C));
+        assert_eq!(kmer.get(3), None);
+
+        assert!(SimpleKmer::<DNA4, U3>::from_bases(vec![A, C]).is_none());
+    }
+
+    #[test]
+    fn test_push() {
+        let mut kmer = SimpleKmer::<DNA4, U3>::from_bases(&[A, C, G]).unwrap();
+        kmer.push_back(&T);
+        assert_eq!(kmer.bases(), vec![C, G, T]);
+
+        kmer.push_front(&A);
+        assert_eq!(kmer.bases(), vec![A, C, G]);
+    }
+
+    #[test]
+    fn test_display() {
+        let kmer = SimpleKmer::<DNA, U4>::from_bases(&[DNA::A, DNA::N, DNA::G, DNA::T]).unwrap();
+        assert_eq!(kmer.to_string(), "ANGT");
+    }
+
+    #[test]
+    fn test_kmers_iter() {
+        let seq = vec![A, C, G, T, T];
+        let kmers: Vec<SimpleKmer<DNA4, U3>> = seq.iter().kmers::<U3>().collect();
+        let bases: Vec<Vec<DNA4>> = kmers.iter().map(|k| k.bases()).collect();
+        assert_eq!(bases, vec![vec![A, C, G], vec![C, G, T], vec![G, T, T]]);
+
+        let kmers: Vec<SimpleKmer<DNA4, U3>> = seq.into_iter().kmers::<U3>().collect();
+        assert_eq!(kmers.len(), 3);
+    }
+
+    #[test]
+    fn test_kmers_len() {
+        let mut kmers = IntoKmers::<DNA4>::kmers::<U3>(vec![A, C, G, T, T].into_iter());
+        assert_eq!(kmers.len(), 3);
+        let _ = kmers.next();
+        assert_eq!(kmers.len(), 2);
+
+        let kmers = IntoKmers::<DNA4>::kmers::<U3>(vec![A, C].into_iter());
+        assert_eq!(kmers.len(), 0);
+    }
+
+    #[test]
+    fn test_reverse_complement() {
+        let kmer = SimpleKmer::<DNA, U4>::from_bases(&[DNA::A, DNA::R, DNA::G, DNA::T]).unwrap();
+        assert_eq!(kmer.complement().bases(), vec![DNA::T, DNA::Y, DNA::C, DNA::A]);
+        assert_eq!(
+            kmer.reverse_complement().bases(),
+            vec![DNA::A, DNA::C, DNA::Y, DNA::T]
+        );
+    }
+
+    #[test]
+    fn test_canonical_kmers_iter() {
+        let seq = vec![A, C, G, T, T];
+        let kmers: Vec<SimpleKmer<DNA4, U3>> = seq.iter().canonical_kmers::<U3>().collect();
+        let bases: Vec<Vec<DNA4>> = kmers.iter().map(|k| k.bases()).collect();
+        assert_eq!(bases, vec![vec![A, C, G], vec![A, C, G], vec![A, A, C]]);
+
+        let kmers = IntoCanonicalKmers::<DNA4>::canonical_kmers::<U3>(seq.into_iter());
+        assert_eq!(kmers.len(), 3);
+    }
+
+    proptest! {
+        #[test]
+        fn test_reverse_complement_twice_recovers_original(
+            seq in vec(select(DNA::variants()), 4),
+        ) {
+            let kmer = SimpleKmer::<DNA, U4>::from_bases(&seq).unwrap();
+            assert_eq!(kmer.reverse_complement().reverse_complement(), kmer);
+        }
+
+        // Rolling canonical kmers should match canonicalising each kmer.
+        #[test]
+        fn test_rolling_canonical_matches_windows(
+            seq in vec(select(DNA4::variants()), 0..50),
+        ) {
+            let rolled: Vec<SimpleKmer<DNA4, U4>> = seq
+                .iter()
+                .canonical_kmers::<U4>()
+                .collect();
+            let windowed: Vec<SimpleKmer<DNA4, U4>> = seq
+                .iter()
+                .kmers::<U4>()
+                .map(|k: SimpleKmer<DNA4, U4>| k.canonical())
+                .collect();
+            assert_eq!(rolled, windowed);
+        }
+
+        #[test]
+        fn test_rank_recovers_original(
+            seq in vec(select(DNA::variants()), 4),
+        ) {
+            let kmer = SimpleKmer::<DNA, U4>::from_bases(&seq).unwrap();
+            let recovered = SimpleKmer::<DNA, U4>::from_rank(kmer.rank()).unwrap();
+            assert_eq!(recovered.bases(), seq);
+        }
+
+        // Rolling kmers should always equal kmers built from scratch.
+        #[test]
+        fn test_rolling_matches_windows(
+            seq in vec(select(AA::variants()), 0..50),
+        ) {
+            let rolled: Vec<SimpleKmer<AA, U3>> = seq.iter().kmers::<U3>().collect();
+            let windowed: Vec<SimpleKmer<AA, U3>> = seq
+                .windows(3)
+                .map(|w| SimpleKmer::from_bases(w).unwrap())
+                .collect();
+            assert_eq!(rolled, windowed);
+        }
+    }
+}
@@ -0,0 +1,517 @@
+//! Spaced-seed kmers, where a binary mask selects which positions in a window
+//! contribute to the kmer.
+//!
+//! A seed like `1101101` spans 7 bases but only has a weight of 5, so the
+//! resulting kmers are 5-mers that tolerate mismatches at the `0` positions.
+//! Using several seeds at once makes homology searches between divergent
+//! sequences more sensitive than contiguous kmers of the same weight.
+
+use std::borrow::Borrow;
+use std::collections::VecDeque;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use typenum::Unsigned;
+
+use crate::alphabet::Alphabet;
+use crate::errors::{SeqError, SeqErrorKind};
+use crate::kmer::SimpleKmer;
+
+/// A binary mask describing which positions of a window make up a kmer.
+///
+/// Seeds must begin and end with a selected position, so that the span of
+/// the seed is unambiguous.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::kmer::SpacedSeed;
+///
+/// let seed: SpacedSeed = "1101101".parse().unwrap();
+/// assert_eq!(seed.span(), 7);
+/// assert_eq!(seed.weight(), 5);
+/// assert_eq!(seed.offsets(), &[0, 1, 3, 4, 6]);
+/// assert_eq!(seed.to_string(), "1101101");
+///
+/// assert!("0110".parse::<SpacedSeed>().is_err());
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct SpacedSeed {
+    offsets: Vec<usize>,
+}
+
+impl SpacedSeed {
+    /// Construct a seed from a mask, where `true` selects a position.
+    ///
+    /// Returns `None` if the mask doesn't start and end with `true`.
+    pub fn new(mask: &[bool]) -> Option<Self> {
+        match (mask.first(), mask.last()) {
+            (Some(true), Some(true)) => {}
+            _ => return None,
+        }
+
+        let offsets = mask
+            .iter()
+            .enumerate()
+            .filter(|(_, &m)| m)
+            .map(|(i, _)| i)
+            .collect();
+
+        Some(SpacedSeed { offsets: offsets })
+    }
+
+    /// A seed of `k` contiguous positions, i.e. an ordinary kmer.
+    pub fn contiguous(k: usize) -> Option<Self> {
+        Self::new(&vec![true; k])
+    }
+
+    /// The number of positions in the window covered by the seed.
+    pub fn span(&self) -> usize {
+        // Seeds always end with a selected position.
+        self.offsets[self.offsets.len() - 1] + 1
+    }
+
+    /// The number of selected positions, which is the size of the kmer.
+    pub fn weight(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// The offsets of the selected positions within the window.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Returns the mask as a Vec of bools.
+    pub fn mask(&self) -> Vec<bool> {
+        let mut mask = vec![false; self.span()];
+        for &o in &self.offsets {
+            mask[o] = true;
+        }
+        mask
+    }
+
+    /// Pack the selected ranks from a window into a kmer.
+    ///
+    /// The ranks are combined the same way as [`Codon::rank`], with the
+    /// first selected base being the most significant.
+    ///
+    /// [`Codon::rank`]: ../codon/struct.Codon.html
+    #[inline]
+    fn pack<A, K>(&self, window: &VecDeque<usize>) -> SimpleKmer<A, K>
+    where
+        A: Alphabet,
+        K: Unsigned,
+    {
+        let inner = self
+            .offsets
+            .iter()
+            .fold(0, |acc, &o| acc * A::cardinality() + window[o]);
+        SimpleKmer::from_inner(inner)
+    }
+
+    /// Extract the spaced kmer starting at the first base of a slice,
+    /// returning `None` if the slice is shorter than the span of the seed.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::alphabet::DNA4;
+    /// use seqrs::alphabet::DNA4::*;
+    /// use seqrs::kmer::{Kmer, SimpleKmer, SpacedSeed};
+    /// use typenum::U3;
+    ///
+    /// let seed: SpacedSeed = "1011".parse().unwrap();
+    /// let kmer: SimpleKmer<DNA4, U3> = seed.extract(&[A, C, G, T]).unwrap();
+    /// assert_eq!(kmer.bases(), vec![A, G, T]);
+    /// ```
+    pub fn extract<A, K>(&self, bases: &[A]) -> Option<SimpleKmer<A, K>>
+    where
+        A: Alphabet,
+        K: Unsigned,
+    {
+        assert_eq!(self.weight(), K::to_usize(), "Seed weight must equal k.");
+
+        if bases.len() < self.span() {
+            return None;
+        }
+
+        let inner = self
+            .offsets
+            .iter()
+            .fold(0, |acc, &o| acc * A::cardinality() + bases[o].rank());
+        Some(SimpleKmer::from_inner(inner))
+    }
+}
+
+impl FromStr for SpacedSeed {
+    type Err = SeqError;
+
+    /// Parse a seed from a string of `1`s and `0`s.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || {
+            SeqError::from(SeqErrorKind::SpacedSeedReadError {
+                pattern: s.to_string(),
+            })
+        };
+
+        let mask: Vec<bool> = s
+            .chars()
+            .map(|c| match c {
+                '1' => Ok(true),
+                '0' => Ok(false),
+                _ => Err(err()),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Self::new(&mask).ok_or_else(err)
+    }
+}
+
+impl fmt::Display for SpacedSeed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s: String = self
+            .mask()
+            .iter()
+            .map(|&m| if m { '1' } else { '0' })
+            .collect();
+        write!(f, "{}", s)
+    }
+}
+
+/// Shift the window along by one base, filling it first if necessary.
+/// Returns `None` when the underlying iterator runs out.
+#[inline]
+fn advance<I, A>(iter: &mut I, window: &mut VecDeque<usize>, span: usize) -> Option<()>
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Alphabet,
+{
+    if window.len() == span {
+        window.pop_front();
+    }
+
+    while window.len() < span {
+        let base = iter.next()?;
+        window.push_back(base.borrow().rank());
+    }
+
+    Some(())
+}
+
+/// Yields iterators over the spaced kmers in another iterator.
+///
+/// This trait is automatically implemented for any iterator over an
+/// [`Alphabet`] (owned or borrowed).
+///
+/// [`Alphabet`]: ../alphabet/trait.Alphabet.html
+pub trait IntoSpacedKmers<A>: Sized {
+    /// Yield the kmer selected by a seed at every window position.
+    ///
+    /// Panics if the weight of the seed isn't `K`.
+    fn spaced_kmers<K: Unsigned>(self, seed: SpacedSeed) -> SpacedKmers<Self, A, K>;
+
+    /// Yield the kmers selected by each of several seeds at every window
+    /// position, in the same order as the seeds.
+    ///
+    /// Windows span the longest seed, so every seed produces the same number
+    /// of kmers. Panics if no seeds are given or any seed has a weight
+    /// other than `K`.
+    fn multi_spaced_kmers<K: Unsigned>(self, seeds: Vec<SpacedSeed>) -> MultiSpacedKmers<Self, A, K>;
+}
+
+impl<I, A> IntoSpacedKmers<A> for I
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Alphabet,
+{
+    fn spaced_kmers<K: Unsigned>(self, seed: SpacedSeed) -> SpacedKmers<Self, A, K> {
+        assert_eq!(seed.weight(), K::to_usize(), "Seed weight must equal k.");
+
+        SpacedKmers {
+            iter: self,
+            window: VecDeque::with_capacity(seed.span()),
+            seed: seed,
+            kmer: PhantomData,
+        }
+    }
+
+    fn multi_spaced_kmers<K: Unsigned>(self, seeds: Vec<SpacedSeed>) -> MultiSpacedKmers<Self, A, K> {
+        assert!(!seeds.is_empty(), "At least one seed is required.");
+        assert!(
+            seeds.iter().all(|s| s.weight() == K::to_usize()),
+            "Seed weight must equal k."
+        );
+
+        let span = seeds.iter().map(|s| s.span()).max().unwrap_or(0);
+
+        MultiSpacedKmers {
+            iter: self,
+            window: VecDeque::with_capacity(span),
+            seeds: seeds,
+            span: span,
+            kmer: PhantomData,
+        }
+    }
+}
+
+/// An iterator adapter over the kmers selected by a spaced seed in every
+/// window of another iterator.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::DNA4;
+/// use seqrs::alphabet::DNA4::*;
+/// use seqrs::kmer::{IntoSpacedKmers, Kmer, SimpleKmer};
+/// use typenum::U3;
+///
+/// let seq = vec![A, C, G, T, A];
+/// let kmers: Vec<SimpleKmer<DNA4, U3>> = seq
+///     .iter()
+///     .spaced_kmers::<U3>("1101".parse().unwrap())
+///     .collect();
+///
+/// assert_eq!(kmers.len(), 2);
+/// assert_eq!(kmers[0].bases(), vec![A, C, T]);
+/// assert_eq!(kmers[1].bases(), vec![C, G, A]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpacedKmers<I, A, K> {
+    iter: I,
+    seed: SpacedSeed,
+    window: VecDeque<usize>,
+    kmer: PhantomData<SimpleKmer<A, K>>,
+}
+
+impl<I, A, K> Iterator for SpacedKmers<I, A, K>
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Alphabet,
+    K: Unsigned,
+{
+    type Item = SimpleKmer<A, K>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        advance(&mut self.iter, &mut self.window, self.seed.span())?;
+        Some(self.seed.pack(&self.window))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let filled = self.window.len();
+        let span = self.seed.span();
+        let adjust = |n: usize| {
+            if filled == span {
+                n
+            } else {
+                (n + filled + 1).saturating_sub(span)
+            }
+        };
+
+        (adjust(lower), upper.map(adjust))
+    }
+}
+
+impl<I, A, K> ExactSizeIterator for SpacedKmers<I, A, K>
+where
+    I: ExactSizeIterator,
+    I::Item: Borrow<A>,
+    A: Alphabet,
+    K: Unsigned,
+{
+}
+
+/// An iterator adapter over the kmers selected by several spaced seeds in
+/// every window of another iterator.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::DNA4;
+/// use seqrs::alphabet::DNA4::*;
+/// use seqrs::kmer::{IntoSpacedKmers, Kmer, SimpleKmer};
+/// use typenum::U2;
+///
+/// let seeds = vec!["11".parse().unwrap(), "101".parse().unwrap()];
+/// let seq = vec![A, C, G, T];
+/// let kmers: Vec<Vec<SimpleKmer<DNA4, U2>>> = seq
+///     .iter()
+///     .multi_spaced_kmers::<U2>(seeds)
+///     .collect();
+///
+/// assert_eq!(kmers.len(), 2);
+/// assert_eq!(kmers[0][0].bases(), vec![A, C]);
+/// assert_eq!(kmers[0][1].bases(), vec![A, G]);
+/// assert_eq!(kmers[1][1].bases(), vec![C, T]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultiSpacedKmers<I, A, K> {
+    iter: I,
+    seeds: Vec<SpacedSeed>,
+    span: usize,
+    window: VecDeque<usize>,
+    kmer: PhantomData<SimpleKmer<A, K>>,
+}
+
+impl<I, A, K> Iterator for MultiSpacedKmers<I, A, K>
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Alphabet,
+    K: Unsigned,
+{
+    type Item = Vec<SimpleKmer<A, K>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        advance(&mut self.iter, &mut self.window, self.span)?;
+        let window = &self.window;
+        Some(self.seeds.iter().map(|s| s.pack(window)).collect())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let filled = self.window.len();
+        let span = self.span;
+        let adjust = |n: usize| {
+            if filled == span {
+                n
+            } else {
+                (n + filled + 1).saturating_sub(span)
+            }
+        };
+
+        (adjust(lower), upper.map(adjust))
+    }
+}
+
+impl<I, A, K> ExactSizeIterator for MultiSpacedKmers<I, A, K>
+where
+    I: ExactSizeIterator,
+    I::Item: Borrow<A>,
+    A: Alphabet,
+    K: Unsigned,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::DNA4;
+    use crate::alphabet::DNA4::*;
+    use crate::kmer::{IntoKmers, Kmer};
+    use proptest::collection::vec;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+    use typenum::{U3, U4};
+
+    #[test]
+    fn test_parse_seed() {
+        let seed: SpacedSeed = "1101".parse().unwrap();
+        assert_eq!(seed.mask(), vec![true, true, false, true]);
+
+        for pattern in &["", "0", "1100", "0011", "11x1"] {
+            let err = pattern.parse::<SpacedSeed>().unwrap_err();
+            assert_eq!(
+                err.kind(),
+                &SeqErrorKind::SpacedSeedReadError {
+                    pattern: pattern.to_string()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_spaced_kmers_len() {
+        let seq = vec![A, C, G, T, A, C];
+        let kmers = IntoSpacedKmers::<DNA4>::spaced_kmers::<U3>(
+            seq.iter(),
+            "1011".parse().unwrap(),
+        );
+        assert_eq!(kmers.len(), 3);
+        assert_eq!(kmers.count(), 3);
+
+        let short = IntoSpacedKmers::<DNA4>::spaced_kmers::<U3>(
+            seq[..3].iter(),
+            "1011".parse().unwrap(),
+        );
+        assert_eq!(short.len(), 0);
+        assert_eq!(short.count(), 0);
+    }
+
+    #[test]
+    fn test_spaced_kmers_len_after_next() {
+        let seq = vec![A, C, G, T, A, C, G];
+        let mut kmers = IntoSpacedKmers::<DNA4>::spaced_kmers::<U3>(
+            seq.iter(),
+            "1101".parse().unwrap(),
+        );
+        kmers.next();
+        assert_eq!(kmers.len(), 3);
+        assert_eq!(kmers.len(), kmers.count());
+
+        let seeds = vec!["1101".parse().unwrap(), "1011".parse().unwrap()];
+        let mut kmers = IntoSpacedKmers::<DNA4>::multi_spaced_kmers::<U3>(seq.iter(), seeds);
+        kmers.next();
+        assert_eq!(kmers.len(), 3);
+        assert_eq!(kmers.len(), kmers.count());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wrong_weight() {
+        let seq = vec![A, C, G, T];
+        let _ = IntoSpacedKmers::<DNA4>::spaced_kmers::<U4>(seq.iter(), "101".parse().unwrap());
+    }
+
+    proptest! {
+        #[test]
+        fn test_contiguous_seed_matches_kmers(seq in vec(select(DNA4::variants()), 0..50)) {
+            let seed = SpacedSeed::contiguous(4).unwrap();
+            let spaced: Vec<SimpleKmer<DNA4, U4>> = seq.iter().spaced_kmers::<U4>(seed).collect();
+            let kmers: Vec<SimpleKmer<DNA4, U4>> = seq.iter().kmers::<U4>().collect();
+            assert_eq!(spaced, kmers);
+        }
+
+        #[test]
+        fn test_spaced_kmers_match_windows(seq in vec(select(DNA4::variants()), 0..50)) {
+            let seed: SpacedSeed = "11001".parse().unwrap();
+            let spaced: Vec<SimpleKmer<DNA4, U3>> = seq.iter().spaced_kmers::<U3>(seed).collect();
+
+            let expected: Vec<Vec<DNA4>> = seq
+                .windows(5)
+                .map(|w| vec![w[0], w[1], w[4]])
+                .collect();
+
+            let spaced: Vec<Vec<DNA4>> = spaced.iter().map(|k| k.bases()).collect();
+            assert_eq!(spaced, expected);
+        }
+
+        #[test]
+        fn test_multi_spaced_kmers_match_extract(seq in vec(select(DNA4::variants()), 0..50)) {
+            let seeds: Vec<SpacedSeed> = vec![
+                "111".parse().unwrap(),
+                "1101".parse().unwrap(),
+                "100101".parse().unwrap(),
+            ];
+
+            let multi: Vec<Vec<SimpleKmer<DNA4, U3>>> = seq
+                .iter()
+                .multi_spaced_kmers::<U3>(seeds.clone())
+                .collect();
+
+            assert_eq!(multi.len(), (seq.len() + 1).saturating_sub(6));
+            for (i, kmers) in multi.iter().enumerate() {
+                for (seed, kmer) in seeds.iter().zip(kmers.iter()) {
+                    assert_eq!(seed.extract(&seq[i..]), Some(*kmer));
+                }
+            }
+        }
+    }
+}
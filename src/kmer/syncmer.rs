@@ -0,0 +1,313 @@
+//! Open and closed syncmers over nucleotide sequences.
+//!
+//! Syncmers (Edgar 2021) select kmers based on the position of the smallest
+//! `s`-mer inside each kmer. Unlike minimizers the decision only depends on
+//! the kmer itself, so the same kmers are selected regardless of their
+//! neighbours.
+
+use std::borrow::Borrow;
+use std::collections::VecDeque;
+use typenum::Unsigned;
+
+use crate::alphabet::Alphabet;
+use crate::complement::Complement;
+use crate::kmer::minimizer::select;
+use crate::kmer::{KmerHasher, KmerPair, Strand, StrandedKmer};
+
+/// The rule deciding which kmers are syncmers, based on the offset of the
+/// smallest `s`-mer in the kmer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncmerKind {
+    /// The smallest `s`-mer starts at the given offset.
+    Open(usize),
+    /// The smallest `s`-mer is the first or last `s`-mer in the kmer.
+    /// If the smallest key occurs more than once, any occurrence at either
+    /// end counts.
+    Closed,
+}
+
+/// Yields an iterator over the syncmers in another iterator.
+///
+/// This trait is automatically implemented for any iterator over an
+/// [`Alphabet`] that implements [`Complement`].
+///
+/// [`Alphabet`]: ../alphabet/trait.Alphabet.html
+/// [`Complement`]: ../complement/trait.Complement.html
+pub trait IntoSyncmers<A>: Sized {
+    fn syncmers<K, S, H>(self, kind: SyncmerKind, hasher: H) -> Syncmers<Self, A, K, S, H>
+    where
+        K: Unsigned,
+        S: Unsigned,
+        H: KmerHasher;
+}
+
+impl<I, A> IntoSyncmers<A> for I
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Alphabet + Complement<Compl = A>,
+{
+    fn syncmers<K, S, H>(self, kind: SyncmerKind, hasher: H) -> Syncmers<Self, A, K, S, H>
+    where
+        K: Unsigned,
+        S: Unsigned,
+        H: KmerHasher,
+    {
+        assert!(
+            S::to_usize() > 0 && S::to_usize() <= K::to_usize(),
+            "Syncmer s-mer size must be between 1 and k."
+        );
+
+        if let SyncmerKind::Open(t) = kind {
+            assert!(
+                t <= K::to_usize() - S::to_usize(),
+                "Open syncmer offset must be at most k - s."
+            );
+        }
+
+        Syncmers {
+            iter: self,
+            kind: kind,
+            hasher: hasher,
+            kmer: KmerPair::new(),
+            smer: KmerPair::new(),
+            position: 0,
+            smer_position: 0,
+            window: VecDeque::with_capacity(K::to_usize() - S::to_usize() + 1),
+        }
+    }
+}
+
+/// An iterator adapter over the open or closed syncmers in another iterator.
+///
+/// `s`-mers are compared using the smaller key of their forward and reverse
+/// complement orientations, so the same kmers are selected from either
+/// strand. If several `s`-mers share the smallest key, the leftmost is used
+/// for open syncmers. Selected kmers are returned in their canonical
+/// orientation, with the strand that orientation was read from.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::DNA4;
+/// use seqrs::alphabet::DNA4::*;
+/// use seqrs::kmer::{IntoSyncmers, Kmer, Lexicographic, StrandedKmer, SyncmerKind};
+/// use typenum::{U2, U4};
+///
+/// let seq = vec![G, A, C, T, T, G];
+/// let syncmers: Vec<StrandedKmer<DNA4, U4>> = seq
+///     .iter()
+///     .syncmers::<U4, U2, _>(SyncmerKind::Closed, Lexicographic)
+///     .collect();
+///
+/// // GACT and CTTG have their smallest s-mers (AC and TT) in the middle.
+/// // ACTT ends with TT, which is AA on the reverse strand.
+/// assert_eq!(syncmers.len(), 1);
+/// assert_eq!(syncmers[0].position, 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Syncmers<I, A, K, S, H> {
+    iter: I,
+    kind: SyncmerKind,
+    hasher: H,
+    kmer: KmerPair<A, K>,
+    smer: KmerPair<A, S>,
+    position: usize,
+    smer_position: usize,
+    window: VecDeque<(u64, usize)>,
+}
+
+impl<I, A, K, S, H> Iterator for Syncmers<I, A, K, S, H>
+where
+    I: Iterator,
+    I::Item: Borrow<A>,
+    A: Alphabet + Complement<Compl = A>,
+    K: Unsigned,
+    S: Unsigned,
+    H: KmerHasher,
+{
+    type Item = StrandedKmer<A, K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let base = self.iter.next()?;
+            let base = base.borrow();
+            let kmer_full = self.kmer.push(base);
+
+            if !self.smer.push(base) {
+                continue;
+            }
+
+            let smer_position = self.smer_position;
+            self.smer_position += 1;
+            let (key, _) = select(&self.hasher, self.smer.fwd, self.smer.rev, smer_position);
+
+            while let Some(&(back, _)) = self.window.back() {
+                if back > key {
+                    self.window.pop_back();
+                } else {
+                    break;
+                }
+            }
+            self.window.push_back((key, smer_position));
+
+            if !kmer_full {
+                continue;
+            }
+
+            let position = self.position;
+            self.position += 1;
+
+            while let Some(&(_, p)) = self.window.front() {
+                if p < position {
+                    self.window.pop_front();
+                } else {
+                    break;
+                }
+            }
+
+            // The window always holds the newest s-mer at the back.
+            let (min, offset) = match self.window.front() {
+                Some(&(key, p)) => (key, p - position),
+                None => continue,
+            };
+
+            let is_syncmer = match self.kind {
+                SyncmerKind::Open(t) => offset == t,
+                SyncmerKind::Closed => {
+                    offset == 0 || self.window.back().map_or(false, |&(key, _)| key == min)
+                }
+            };
+
+            if is_syncmer {
+                // The hasher only covers s-mers, so orient the kmer by rank.
+                let (fwd, rev) = (self.kmer.fwd, self.kmer.rev);
+                let kmer = if rev.rank() < fwd.rank() {
+                    StrandedKmer::new(rev, position, Strand::Reverse)
+                } else {
+                    StrandedKmer::new(fwd, position, Strand::Forward)
+                };
+                return Some(kmer);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::DNA4;
+    use crate::alphabet::DNA4::*;
+    use crate::complement::IntoReverseComplement;
+    use crate::kmer::{IntoKmers, InvertibleHash, Kmer, Lexicographic, SimpleKmer};
+    use proptest::collection::vec;
+    use proptest::sample::select as choose;
+    use proptest::{proptest, proptest_helper};
+    use typenum::{U2, U3, U7};
+
+    /// Find the offsets of the smallest s-mers in a kmer by brute force.
+    fn naive_offsets<H: KmerHasher>(kmer: &SimpleKmer<DNA4, U7>, hasher: &H) -> Vec<usize> {
+        let bases = kmer.bases();
+        let keys: Vec<u64> = bases
+            .iter()
+            .kmers::<U3>()
+            .map(|smer: SimpleKmer<DNA4, U3>| {
+                select(hasher, smer, smer.reverse_complement(), 0).0
+            })
+            .collect();
+
+        let min = keys.iter().min().unwrap();
+        (0..keys.len()).filter(|&i| keys[i] == *min).collect()
+    }
+
+    #[test]
+    fn test_open_syncmers() {
+        let seq = vec![G, A, C, T, T, G];
+        let syncmers: Vec<usize> = seq
+            .iter()
+            .syncmers::<U3, U2, _>(SyncmerKind::Open(1), Lexicographic)
+            .map(|s: StrandedKmer<DNA4, U3>| s.position)
+            .collect();
+
+        // GAC and CTT have their smallest 2-mers (AC and TT) at offset 1.
+        assert_eq!(syncmers, vec![0, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_open_offset_out_of_range() {
+        let seq = vec![G, A, C, T, T, G];
+        let _: Vec<StrandedKmer<DNA4, U3>> = seq
+            .iter()
+            .syncmers::<U3, U2, _>(SyncmerKind::Open(2), Lexicographic)
+            .collect();
+    }
+
+    proptest! {
+        #[test]
+        fn test_syncmers_match_naive(
+            seq in vec(choose(DNA4::variants()), 0..100),
+            t in 0_usize..5,
+        ) {
+            let hasher = InvertibleHash::new(6);
+            let kmers: Vec<SimpleKmer<DNA4, U7>> = seq.iter().kmers::<U7>().collect();
+
+            for &kind in &[SyncmerKind::Open(t), SyncmerKind::Closed] {
+                let found: Vec<usize> = seq
+                    .iter()
+                    .syncmers::<U7, U3, _>(kind, hasher)
+                    .map(|s: StrandedKmer<DNA4, U7>| s.position)
+                    .collect();
+
+                let expected: Vec<usize> = kmers
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, k)| {
+                        let offsets = naive_offsets(k, &hasher);
+                        match kind {
+                            SyncmerKind::Open(t) => offsets[0] == t,
+                            SyncmerKind::Closed => offsets[0] == 0 || offsets.contains(&4),
+                        }
+                    })
+                    .map(|(i, _)| i)
+                    .collect();
+
+                assert_eq!(found, expected);
+            }
+        }
+
+        // Closed syncmers are symmetric, so both strands select the same kmers.
+        #[test]
+        fn test_closed_syncmers_strand_independent(
+            seq in vec(choose(DNA4::variants()), 0..100),
+        ) {
+            let hasher = InvertibleHash::new(6);
+            let kmers: Vec<SimpleKmer<DNA4, U7>> = seq.iter().kmers::<U7>().collect();
+
+            let mut fwd = Vec::new();
+            for s in seq.iter().syncmers::<U7, U3, _>(SyncmerKind::Closed, hasher) {
+                let kmer = kmers[s.position];
+                let rc = kmer.reverse_complement();
+                if rc.rank() < kmer.rank() {
+                    assert_eq!(s.strand, Strand::Reverse);
+                    assert_eq!(s.kmer, rc);
+                } else {
+                    assert_eq!(s.strand, Strand::Forward);
+                    assert_eq!(s.kmer, kmer);
+                }
+                fwd.push(s.kmer);
+            }
+
+            let mut rev: Vec<SimpleKmer<DNA4, U7>> = seq
+                .iter()
+                .reverse_complement()
+                .syncmers::<U7, U3, _>(SyncmerKind::Closed, hasher)
+                .map(|s| s.kmer)
+                .collect();
+
+            fwd.sort();
+            rev.sort();
+            assert_eq!(fwd, rev);
+        }
+    }
+}
@@ -10,13 +10,22 @@ pub mod utils;
 #[macro_use]
 pub mod macros;
 
+pub mod aa_properties;
 pub mod alphabet;
 pub mod errors;
 
 pub mod codon;
 pub mod complement;
 pub mod gapped;
+pub mod masked;
+pub mod packed;
+pub mod parse;
 pub mod stopped;
+pub mod quality;
+pub mod seq;
 pub mod translate;
+pub mod twobit;
 pub mod matcher;
 pub mod kmer;
+pub mod debruijn;
+pub mod sketch;
@@ -314,7 +314,11 @@ macro_rules! alphabet {
             fn try_from(base: &u8) -> Result<Self, Self::Error> {
                 match base.to_ascii_uppercase() {
                     $($byte => Ok($name::$variant),)*
-                    b => Err(SeqErrorKind::AlphabetReadError { base: b as char }.into()),
+                    b => Err(SeqErrorKind::AlphabetReadError {
+                        base: b as char,
+                        position: $crate::errors::Position(None),
+                    }
+                    .into()),
                 }
             }
         }
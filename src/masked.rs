@@ -0,0 +1,525 @@
+//! Generalised soft-masked alphabets.
+//!
+//! Reference genomes commonly mark repeats by writing them in lowercase
+//! ("soft-masking"). The alphabets in this crate are case insensitive, so
+//! [`Masked`] wraps a base and records whether it was masked.
+//!
+//! # Examples:
+//!
+//! ```
+//! use seqrs::alphabet::DNA;
+//! use seqrs::masked::{masked_intervals, IntoHardMasked, Masked};
+//! use std::convert::TryFrom;
+//!
+//! let seq: Vec<Masked<DNA>> = b"ACgtaN"
+//!     .iter()
+//!     .map(|b| Masked::try_from(b).unwrap())
+//!     .collect();
+//!
+//! assert_eq!(masked_intervals(&seq), vec![2..5]);
+//!
+//! let hard: Vec<DNA> = seq.iter().hard_masked(DNA::N).collect();
+//! assert_eq!(hard, vec![DNA::A, DNA::C, DNA::N, DNA::N, DNA::N, DNA::N]);
+//!
+//! let bytes: Vec<u8> = seq.iter().map(u8::from).collect();
+//! assert_eq!(bytes, b"ACgtaN".to_vec());
+//! ```
+//!
+//! [`Masked`]: enum.Masked.html
+
+use std::borrow::Borrow;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Range;
+
+use crate::codon::Codon;
+use crate::complement::Complement;
+use crate::translate::CodonTagTable;
+use crate::translate::TranslationTable;
+
+/// A soft-masked alphabet wraps each base in either [`Base`] for regular
+/// (uppercase) bases or [`Soft`] for masked (lowercase) bases.
+///
+/// [`Base`]: #variant.Base
+/// [`Soft`]: #variant.Soft
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum Masked<T> {
+    Base(T),
+    Soft(T),
+}
+
+impl<T> Masked<T> {
+    /// Returns true if is the [`Soft`] variant.
+    ///
+    /// [`Soft`]: #variant.Soft
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::masked::Masked::*;
+    ///
+    /// assert_eq!(Soft('a').is_masked(), true);
+    /// assert_eq!(Base('a').is_masked(), false);
+    /// ```
+    #[inline]
+    pub fn is_masked(&self) -> bool {
+        match *self {
+            Masked::Soft(_) => true,
+            Masked::Base(_) => false,
+        }
+    }
+
+    /// Converts from `Masked<T>` to `Masked<&T>`.
+    #[inline]
+    pub fn as_ref(&self) -> Masked<&T> {
+        match *self {
+            Masked::Base(ref x) => Masked::Base(x),
+            Masked::Soft(ref x) => Masked::Soft(x),
+        }
+    }
+
+    /// Returns a reference to the wrapped base.
+    #[inline]
+    pub fn base(&self) -> &T {
+        match *self {
+            Masked::Base(ref x) | Masked::Soft(ref x) => x,
+        }
+    }
+
+    /// Unwraps the base, discarding the mask.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        match self {
+            Masked::Base(x) | Masked::Soft(x) => x,
+        }
+    }
+
+    /// Maps a `Masked<T>` to `Masked<U>`, keeping the mask.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::masked::Masked::*;
+    ///
+    /// assert_eq!(Soft('a').map(|b| b as u8), Soft(b'a'));
+    /// ```
+    #[inline]
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Masked<U> {
+        match self {
+            Masked::Base(x) => Masked::Base(f(x)),
+            Masked::Soft(x) => Masked::Soft(f(x)),
+        }
+    }
+
+    /// Returns the base, replacing masked bases with `n`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::alphabet::DNA;
+    /// use seqrs::masked::Masked::*;
+    ///
+    /// assert_eq!(Soft(DNA::A).hard_mask(DNA::N), DNA::N);
+    /// assert_eq!(Base(DNA::A).hard_mask(DNA::N), DNA::A);
+    /// ```
+    #[inline]
+    pub fn hard_mask(self, n: T) -> T {
+        match self {
+            Masked::Base(x) => x,
+            Masked::Soft(_) => n,
+        }
+    }
+}
+
+impl<'a, T> TryFrom<&'a u8> for Masked<T>
+where
+    T: TryFrom<&'a u8>,
+{
+    type Error = T::Error;
+
+    /// Parse a byte, recording lowercase bytes as masked.
+    fn try_from(base: &'a u8) -> Result<Self, Self::Error> {
+        let masked = base.is_ascii_lowercase();
+        T::try_from(base).map(|b| if masked { Masked::Soft(b) } else { Masked::Base(b) })
+    }
+}
+
+impl<T: TryFrom<u8>> TryFrom<u8> for Masked<T> {
+    type Error = T::Error;
+
+    /// Parse an owned byte, recording lowercase bytes as masked.
+    fn try_from(base: u8) -> Result<Self, Self::Error> {
+        let masked = base.is_ascii_lowercase();
+        T::try_from(base).map(|b| if masked { Masked::Soft(b) } else { Masked::Base(b) })
+    }
+}
+
+impl<'a, T: TryFrom<&'a char>> TryFrom<&'a char> for Masked<T> {
+    type Error = T::Error;
+
+    /// Parse a character, recording lowercase characters as masked.
+    fn try_from(base: &'a char) -> Result<Self, Self::Error> {
+        let masked = base.is_ascii_lowercase();
+        T::try_from(base).map(|b| if masked { Masked::Soft(b) } else { Masked::Base(b) })
+    }
+}
+
+impl<T: TryFrom<char>> TryFrom<char> for Masked<T> {
+    type Error = T::Error;
+
+    /// Parse an owned character, recording lowercase characters as masked.
+    fn try_from(base: char) -> Result<Self, Self::Error> {
+        let masked = base.is_ascii_lowercase();
+        T::try_from(base).map(|b| if masked { Masked::Soft(b) } else { Masked::Base(b) })
+    }
+}
+
+impl<T: Into<u8> + Copy> From<&Masked<T>> for u8 {
+    /// Convert masked alphabet to byte representation, with masked bases
+    /// in lowercase.
+    fn from(base: &Masked<T>) -> Self {
+        match base {
+            Masked::Base(x) => (*x).into(),
+            Masked::Soft(x) => (*x).into().to_ascii_lowercase(),
+        }
+    }
+}
+
+impl<T: Into<u8> + Copy> From<Masked<T>> for u8 {
+    /// Convert masked alphabet to byte representation, with masked bases
+    /// in lowercase.
+    fn from(base: Masked<T>) -> Self {
+        u8::from(&base)
+    }
+}
+
+impl<T: Into<char> + Copy> From<&Masked<T>> for char {
+    /// Convert masked alphabet to char representation, with masked bases
+    /// in lowercase.
+    fn from(base: &Masked<T>) -> Self {
+        match base {
+            Masked::Base(x) => (*x).into(),
+            Masked::Soft(x) => (*x).into().to_ascii_lowercase(),
+        }
+    }
+}
+
+impl<T: Into<char> + Copy> From<Masked<T>> for char {
+    /// Convert masked alphabet to char representation, with masked bases
+    /// in lowercase.
+    fn from(base: Masked<T>) -> Self {
+        char::from(&base)
+    }
+}
+
+impl<T> Complement for Masked<T>
+where
+    T: Complement,
+{
+    type Compl = Masked<<T as Complement>::Compl>;
+
+    fn complement(&self) -> Self::Compl {
+        self.as_ref().map(|a| a.complement())
+    }
+}
+
+impl<K, V, T> TranslationTable<Masked<K>, Masked<V>> for T
+where
+    T: TranslationTable<K, V>,
+{
+    fn get(&self, k: &Masked<K>) -> Masked<V> {
+        k.as_ref()
+            .map(|a| <T as TranslationTable<K, V>>::get(self, a))
+    }
+}
+
+impl<K, V, T> CodonTagTable<Masked<K>, Masked<V>> for T
+where
+    T: CodonTagTable<K, V>,
+{
+    fn get_tag(&self, k: &Masked<K>) -> Masked<V> {
+        k.as_ref()
+            .map(|a| <T as CodonTagTable<K, V>>::get_tag(self, a))
+    }
+}
+
+/// Unwrap a codon of masked bases, returning whether any of them were masked.
+fn unmask_codon<A: Copy>(k: &Codon<Masked<A>>) -> (Codon<A>, bool) {
+    let masked = k.first().is_masked() || k.second().is_masked() || k.third().is_masked();
+    let codon = Codon(*k.first().base(), *k.second().base(), *k.third().base());
+    (codon, masked)
+}
+
+/// Translates codons of masked bases. The result is masked if any of the
+/// bases in the codon were masked.
+impl<A, V, T> TranslationTable<Codon<Masked<A>>, Masked<V>> for T
+where
+    A: Copy,
+    T: TranslationTable<Codon<A>, V>,
+{
+    fn get(&self, k: &Codon<Masked<A>>) -> Masked<V> {
+        let (codon, masked) = unmask_codon(k);
+        let v = <T as TranslationTable<Codon<A>, V>>::get(self, &codon);
+        if masked {
+            Masked::Soft(v)
+        } else {
+            Masked::Base(v)
+        }
+    }
+}
+
+/// Tags codons of masked bases. The result is masked if any of the bases in
+/// the codon were masked.
+impl<A, V, T> CodonTagTable<Codon<Masked<A>>, Masked<V>> for T
+where
+    A: Copy,
+    T: CodonTagTable<Codon<A>, V>,
+{
+    fn get_tag(&self, k: &Codon<Masked<A>>) -> Masked<V> {
+        let (codon, masked) = unmask_codon(k);
+        let v = <T as CodonTagTable<Codon<A>, V>>::get_tag(self, &codon);
+        if masked {
+            Masked::Soft(v)
+        } else {
+            Masked::Base(v)
+        }
+    }
+}
+
+impl<T> fmt::Display for Masked<T>
+where
+    T: Into<char> + Copy,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", char::from(self))
+    }
+}
+
+/// Find the half-open intervals of consecutive masked bases in a sequence.
+pub fn masked_intervals<T, I>(seq: I) -> Vec<Range<usize>>
+where
+    I: IntoIterator,
+    I::Item: Borrow<Masked<T>>,
+{
+    let mut intervals = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut len = 0;
+
+    for (i, base) in seq.into_iter().enumerate() {
+        match (base.borrow().is_masked(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                intervals.push(s..i);
+                start = None;
+            }
+            _ => (),
+        }
+        len = i + 1;
+    }
+
+    if let Some(s) = start {
+        intervals.push(s..len);
+    }
+
+    intervals
+}
+
+/// Replaces masked bases in a sequence with a given letter (e.g. `N`).
+///
+/// This trait is automatically implemented for any iterator over [`Masked`]
+/// bases (owned or borrowed).
+///
+/// [`Masked`]: enum.Masked.html
+pub trait IntoHardMasked<T>: Iterator + Sized {
+    fn hard_masked(self, n: T) -> HardMasked<Self, T>;
+}
+
+impl<T, I> IntoHardMasked<T> for I
+where
+    I: Iterator,
+    I::Item: Borrow<Masked<T>>,
+    T: Copy,
+{
+    fn hard_masked(self, n: T) -> HardMasked<Self, T> {
+        HardMasked { iter: self, n: n }
+    }
+}
+
+/// An iterator adapter yielding bases with masked bases replaced.
+#[derive(Debug, Clone)]
+pub struct HardMasked<I, T> {
+    iter: I,
+    n: T,
+}
+
+impl<T, I> Iterator for HardMasked<I, T>
+where
+    I: Iterator,
+    I::Item: Borrow<Masked<T>>,
+    T: Copy,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let n = &self.n;
+        self.iter.next().map(|b| *b.borrow().as_ref().hard_mask(n))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, I> DoubleEndedIterator for HardMasked<I, T>
+where
+    I: DoubleEndedIterator,
+    I::Item: Borrow<Masked<T>>,
+    T: Copy,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        let n = &self.n;
+        self.iter.next_back().map(|b| *b.borrow().as_ref().hard_mask(n))
+    }
+}
+
+impl<T, I> ExactSizeIterator for HardMasked<I, T>
+where
+    I: ExactSizeIterator,
+    I::Item: Borrow<Masked<T>>,
+    T: Copy,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Masked::*;
+    use super::*;
+    use crate::alphabet::{AA, DNA, DNA4};
+    use crate::codon::IntoCodons;
+    use crate::complement::IntoReverseComplement;
+    use crate::gapped::Gapped;
+    use crate::stopped::Stopped;
+    use crate::translate::{IntoTranslate, NCBITransTable};
+    use proptest::collection::vec;
+    use proptest::prelude::any;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_size() {
+        assert_eq!(std::mem::size_of::<Masked<DNA>>(), 2);
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Masked::<DNA>::try_from(b'a').unwrap(), Soft(DNA::A));
+        assert_eq!(Masked::<DNA>::try_from(&b'A').unwrap(), Base(DNA::A));
+        assert_eq!(Masked::<DNA>::try_from('t').unwrap(), Soft(DNA::T));
+        assert_eq!(Masked::<DNA>::try_from(&'T').unwrap(), Base(DNA::T));
+        assert!(Masked::<DNA>::try_from(b'x').is_err());
+
+        assert_eq!(u8::from(Soft(DNA::T)), b't');
+        assert_eq!(char::from(&Base(DNA::T)), 'T');
+        assert_eq!(format!("{}", Soft(DNA::G)), "g");
+    }
+
+    #[test]
+    fn test_gapped() {
+        let seq: Vec<Gapped<Masked<DNA>>> = "Ac-g"
+            .chars()
+            .map(|c| Gapped::try_from(c).unwrap())
+            .collect();
+
+        assert_eq!(
+            seq,
+            vec![
+                Gapped::Base(Base(DNA::A)),
+                Gapped::Base(Soft(DNA::C)),
+                Gapped::Gap,
+                Gapped::Base(Soft(DNA::G)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reverse_complement() {
+        let seq = vec![Base(DNA4::A), Soft(DNA4::C), Soft(DNA4::T)];
+        let rc: Vec<Masked<DNA4>> = seq.iter().reverse_complement().collect();
+        assert_eq!(rc, vec![Soft(DNA4::A), Soft(DNA4::G), Base(DNA4::T)]);
+    }
+
+    #[test]
+    fn test_translate() {
+        let seq = vec![
+            Base(DNA4::A),
+            Base(DNA4::T),
+            Base(DNA4::G),
+            Base(DNA4::T),
+            Soft(DNA4::G),
+            Soft(DNA4::G),
+        ];
+
+        let aa: Vec<Masked<Stopped<AA>>> = seq
+            .into_iter()
+            .codons()
+            .translate(NCBITransTable::Standard)
+            .collect();
+
+        assert_eq!(
+            aa,
+            vec![Base(Stopped::Res(AA::M)), Soft(Stopped::Res(AA::W))]
+        );
+    }
+
+    #[test]
+    fn test_masked_intervals() {
+        let seq: Vec<Masked<DNA>> = "aaCCgTtt"
+            .chars()
+            .map(|c| Masked::try_from(c).unwrap())
+            .collect();
+        assert_eq!(masked_intervals(&seq), vec![0..2, 4..5, 6..8]);
+
+        let empty: Vec<Masked<DNA>> = Vec::new();
+        assert!(masked_intervals(&empty).is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn test_from_u8_doesnt_crash(c in any::<u8>()) {
+            let _dummy = Masked::<DNA>::try_from(c);
+        }
+
+        // Parsing and writing bytes should preserve case.
+        #[test]
+        fn test_u8_roundtrip(
+            seq in vec(select(b"ACGTNacgtn".to_vec()), 0..50)
+        ) {
+            let masked: Vec<Masked<DNA>> = seq
+                .iter()
+                .map(|b| Masked::try_from(b).unwrap())
+                .collect();
+            let bytes: Vec<u8> = masked.iter().map(u8::from).collect();
+            assert_eq!(bytes, seq);
+
+            // Intervals cover exactly the lowercase bytes.
+            let n_masked: usize = masked_intervals(&masked).iter().map(|r| r.len()).sum();
+            assert_eq!(n_masked, seq.iter().filter(|b| b.is_ascii_lowercase()).count());
+
+            let hard: Vec<DNA> = masked.iter().hard_masked(DNA::N).collect();
+            for (h, b) in hard.iter().zip(seq.iter()) {
+                if b.is_ascii_lowercase() {
+                    assert_eq!(h, &DNA::N);
+                } else {
+                    assert_eq!(u8::from(h), *b);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,820 @@
+//! Bit-packed sequence storage.
+//!
+//! Alphabets with small fixed width encodings can be stored several letters
+//! to a `u64` word, rather than one letter per byte. [`PackedSeq`] stores
+//! letters of any [`PackedAlphabet`], with the first letter in the least
+//! significant bits of the first word.
+//!
+//! # Examples:
+//!
+//! ```
+//! use seqrs::alphabet::{AA, DNA4};
+//! use seqrs::codon::IntoCodons;
+//! use seqrs::packed::PackedSeq;
+//! use seqrs::stopped::Stopped;
+//! use seqrs::translate::{IntoTranslate, NCBITransTable};
+//!
+//! let seq: PackedSeq<DNA4> = "ATGGCCTAA".parse().unwrap();
+//! assert_eq!(seq.get(4), Some(DNA4::C));
+//! assert_eq!(seq.words().len(), 1);
+//!
+//! let protein: Vec<Stopped<AA>> = seq
+//!     .iter()
+//!     .codons()
+//!     .translate(NCBITransTable::Standard)
+//!     .collect();
+//! assert_eq!(protein, vec![Stopped::Res(AA::M), Stopped::Res(AA::A), Stopped::Stop]);
+//!
+//! assert_eq!(seq.reverse_complement().to_string(), "TTAGGCCAT");
+//! assert_eq!(seq.slice(3..6).to_string(), "GCC");
+//! ```
+//!
+//! [`PackedSeq`]: struct.PackedSeq.html
+//! [`PackedAlphabet`]: trait.PackedAlphabet.html
+
+use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+use std::str::FromStr;
+
+use crate::alphabet::{Alphabet, RedundantAlphabet, DNA, DNA4};
+use crate::errors::SeqError;
+use crate::matcher::Match;
+
+/// An alphabet that can be stored in a fixed number of bits per letter.
+///
+/// # Safety
+///
+/// Packed sequences convert stored bits back into letters with
+/// `from_bits_unsafe` from safe code, so implementations must guarantee that:
+///
+/// - `BITS` divides 64.
+/// - `to_bits` always returns a value below `1 << BITS`, which
+///   `from_bits_unsafe` converts back into a valid letter.
+/// - `complement_word` and `reverse_word` map the bits of valid letters to
+///   the bits of valid letters.
+pub unsafe trait PackedAlphabet: Alphabet + Copy {
+    /// The number of bits used to store each letter.
+    /// Must divide 64.
+    const BITS: usize;
+
+    /// The bits representing this letter.
+    fn to_bits(&self) -> u64;
+
+    /// Convert bits back into a letter.
+    ///
+    /// # Safety
+    ///
+    /// The bits must have been created by `to_bits`.
+    unsafe fn from_bits_unsafe(bits: u64) -> Self;
+
+    /// Complement every letter in a packed word.
+    ///
+    /// Unused bits in the word may be set to anything.
+    fn complement_word(word: u64) -> u64;
+
+    /// Reverse the order of the letters in a packed word.
+    fn reverse_word(word: u64) -> u64;
+}
+
+unsafe impl PackedAlphabet for DNA4 {
+    const BITS: usize = 2;
+
+    #[inline]
+    fn to_bits(&self) -> u64 {
+        *self as u64
+    }
+
+    #[inline]
+    unsafe fn from_bits_unsafe(bits: u64) -> Self {
+        std::mem::transmute::<u8, DNA4>(bits as u8)
+    }
+
+    /// The complement of a DNA4 base is its bitwise NOT.
+    #[inline]
+    fn complement_word(word: u64) -> u64 {
+        !word
+    }
+
+    #[inline]
+    fn reverse_word(word: u64) -> u64 {
+        let word = word.swap_bytes();
+        let word = ((word >> 4) & 0x0F0F_0F0F_0F0F_0F0F) | ((word & 0x0F0F_0F0F_0F0F_0F0F) << 4);
+        ((word >> 2) & 0x3333_3333_3333_3333) | ((word & 0x3333_3333_3333_3333) << 2)
+    }
+}
+
+unsafe impl PackedAlphabet for DNA {
+    const BITS: usize = 4;
+
+    #[inline]
+    fn to_bits(&self) -> u64 {
+        *self as u64
+    }
+
+    #[inline]
+    unsafe fn from_bits_unsafe(bits: u64) -> Self {
+        std::mem::transmute::<u8, DNA>(bits as u8)
+    }
+
+    /// The complement of a one-hot DNA base reverses the bits in its nibble,
+    /// so that `A = 0b0001` and `T = 0b1000` are swapped.
+    #[inline]
+    fn complement_word(word: u64) -> u64 {
+        let word = ((word >> 1) & 0x5555_5555_5555_5555) | ((word & 0x5555_5555_5555_5555) << 1);
+        ((word >> 2) & 0x3333_3333_3333_3333) | ((word & 0x3333_3333_3333_3333) << 2)
+    }
+
+    #[inline]
+    fn reverse_word(word: u64) -> u64 {
+        let word = word.swap_bytes();
+        ((word >> 4) & 0x0F0F_0F0F_0F0F_0F0F) | ((word & 0x0F0F_0F0F_0F0F_0F0F) << 4)
+    }
+}
+
+/// A sequence stored with several letters to each `u64` word.
+///
+/// Bits past the end of the sequence in the last word are always zero.
+#[derive(Debug)]
+pub struct PackedSeq<A> {
+    words: Vec<u64>,
+    len: usize,
+    alphabet: PhantomData<A>,
+}
+
+impl<A: PackedAlphabet> PackedSeq<A> {
+    /// The number of letters stored in each word.
+    #[inline]
+    pub fn per_word() -> usize {
+        64 / A::BITS
+    }
+
+    #[inline]
+    fn mask() -> u64 {
+        (1 << A::BITS) - 1
+    }
+
+    #[inline]
+    fn n_words(len: usize) -> usize {
+        (len + Self::per_word() - 1) / Self::per_word()
+    }
+
+    pub fn new() -> Self {
+        PackedSeq {
+            words: Vec::new(),
+            len: 0,
+            alphabet: PhantomData,
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        PackedSeq {
+            words: Vec::with_capacity(Self::n_words(capacity)),
+            len: 0,
+            alphabet: PhantomData,
+        }
+    }
+
+    /// Parse a sequence from bytes, failing at the first invalid letter.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SeqError>
+    where
+        A: for<'a> TryFrom<&'a u8, Error = SeqError>,
+    {
+        let mut seq = Self::with_capacity(bytes.len());
+        for (i, b) in bytes.iter().enumerate() {
+            seq.push(A::try_from(b).map_err(|e| e.at_position(i))?);
+        }
+        Ok(seq)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The packed words, with the first letter in the least significant
+    /// bits of the first word.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    #[inline]
+    fn get_unchecked(&self, index: usize) -> A {
+        let word = self.words[index / Self::per_word()];
+        let shift = (index % Self::per_word()) * A::BITS;
+        unsafe { A::from_bits_unsafe((word >> shift) & Self::mask()) }
+    }
+
+    pub fn get(&self, index: usize) -> Option<A> {
+        if index < self.len {
+            Some(self.get_unchecked(index))
+        } else {
+            None
+        }
+    }
+
+    /// Replace the letter at `index`.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, base: A) {
+        assert!(index < self.len, "Index {} out of bounds.", index);
+        let word = &mut self.words[index / Self::per_word()];
+        let shift = (index % Self::per_word()) * A::BITS;
+        *word = (*word & !(Self::mask() << shift)) | (base.to_bits() << shift);
+    }
+
+    /// Set every letter in a range to `base`, a whole word at a time.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn fill<R: RangeBounds<usize>>(&mut self, range: R, base: A) {
+        let (start, end) = resolve_range(range, self.len);
+        let per_word = Self::per_word();
+        let pattern = (0..per_word).fold(0, |w, _| (w << A::BITS) | base.to_bits());
+
+        let mut i = start;
+        while i < end {
+            let offset = i % per_word;
+            let n = (per_word - offset).min(end - i);
+            let mask = if n == per_word {
+                !0
+            } else {
+                ((1 << (n * A::BITS)) - 1) << (offset * A::BITS)
+            };
+
+            let word = &mut self.words[i / per_word];
+            *word = (*word & !mask) | (pattern & mask);
+            i += n;
+        }
+    }
+
+    pub fn push(&mut self, base: A) {
+        let offset = self.len % Self::per_word();
+        if offset == 0 {
+            self.words.push(0);
+        }
+
+        let last = self.words.len() - 1;
+        self.words[last] |= base.to_bits() << (offset * A::BITS);
+        self.len += 1;
+    }
+
+    pub fn iter(&self) -> PackedIter<'_, A> {
+        PackedIter {
+            seq: self,
+            start: 0,
+            end: self.len,
+        }
+    }
+
+    /// A view of part of the sequence.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> PackedSlice<'_, A> {
+        let (start, end) = resolve_range(range, self.len);
+        PackedSlice {
+            seq: self,
+            start: start,
+            end: end,
+        }
+    }
+
+    /// The reverse complement of the sequence.
+    ///
+    /// Letters are reversed and complemented a whole word at a time.
+    pub fn reverse_complement(&self) -> Self {
+        let mut words: Vec<u64> = self
+            .words
+            .iter()
+            .rev()
+            .map(|w| A::complement_word(A::reverse_word(*w)))
+            .collect();
+
+        // The unused space at the end of the last word is now at the start
+        // of the first word, so shift everything down to fill it.
+        let shift = (words.len() * Self::per_word() - self.len) * A::BITS;
+        if shift > 0 {
+            for i in 0..words.len() {
+                let carry = words.get(i + 1).map_or(0, |w| w << (64 - shift));
+                words[i] = (words[i] >> shift) | carry;
+            }
+        }
+
+        PackedSeq {
+            words: words,
+            len: self.len,
+            alphabet: PhantomData,
+        }
+    }
+}
+
+impl<A: PackedAlphabet> Default for PackedSeq<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> Clone for PackedSeq<A> {
+    fn clone(&self) -> Self {
+        PackedSeq {
+            words: self.words.clone(),
+            len: self.len,
+            alphabet: PhantomData,
+        }
+    }
+}
+
+impl<A> PartialEq for PackedSeq<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.words == other.words
+    }
+}
+
+impl<A> Eq for PackedSeq<A> {}
+
+impl<A> Hash for PackedSeq<A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        self.words.hash(state);
+    }
+}
+
+impl<A: PackedAlphabet> FromIterator<A> for PackedSeq<A> {
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        let mut seq = Self::new();
+        seq.extend(iter);
+        seq
+    }
+}
+
+impl<A: PackedAlphabet> Extend<A> for PackedSeq<A> {
+    fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+        for b in iter {
+            self.push(b);
+        }
+    }
+}
+
+impl<'a, A: PackedAlphabet> IntoIterator for &'a PackedSeq<A> {
+    type Item = A;
+    type IntoIter = PackedIter<'a, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<A> FromStr for PackedSeq<A>
+where
+    A: PackedAlphabet + for<'a> TryFrom<&'a u8, Error = SeqError>,
+{
+    type Err = SeqError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl<A> std::fmt::Display for PackedSeq<A>
+where
+    A: PackedAlphabet,
+    for<'a> char: From<&'a A>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.slice(..).fmt(f)
+    }
+}
+
+// Nibble packed DNA uses the one-hot layout of `DNA`, so that set operations
+// between bases are bitwise operations on whole words.
+
+const LOW_NIBBLE_BITS: u64 = 0x1111_1111_1111_1111;
+
+/// Set the lowest bit of each nibble that has any bits set.
+#[inline]
+fn nonzero_nibbles(word: u64) -> u64 {
+    (word | (word >> 1) | (word >> 2) | (word >> 3)) & LOW_NIBBLE_BITS
+}
+
+impl PackedSeq<DNA> {
+    /// The lowest bit of each nibble used by the sequence in word `i`.
+    #[inline]
+    fn used_nibbles(&self, i: usize) -> u64 {
+        let rem = self.len % Self::per_word();
+        if i + 1 == self.words.len() && rem != 0 {
+            LOW_NIBBLE_BITS & ((1 << (rem * 4)) - 1)
+        } else {
+            LOW_NIBBLE_BITS
+        }
+    }
+
+    /// Apply a bitwise operation to the words of two sequences, returning
+    /// `None` if any position is left empty.
+    ///
+    /// Panics if the sequences have different lengths.
+    fn zip_words<F>(&self, other: &Self, f: F) -> Option<Self>
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        assert_eq!(self.len, other.len, "Sequences have different lengths.");
+
+        let mut words = Vec::with_capacity(self.words.len());
+        for (i, (a, b)) in self.words.iter().zip(other.words.iter()).enumerate() {
+            let word = f(*a, *b);
+            if nonzero_nibbles(word) != self.used_nibbles(i) {
+                return None;
+            }
+            words.push(word);
+        }
+
+        Some(PackedSeq {
+            words: words,
+            len: self.len,
+            alphabet: PhantomData,
+        })
+    }
+
+    /// The number of positions where the two sequences match.
+    ///
+    /// Panics if the sequences have different lengths.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::alphabet::DNA;
+    /// use seqrs::packed::PackedSeq;
+    ///
+    /// let a: PackedSeq<DNA> = "ACGTNA".parse().unwrap();
+    /// let b: PackedSeq<DNA> = "AYKAAC".parse().unwrap();
+    /// assert_eq!(a.count_matches(&b), 4);
+    /// ```
+    pub fn count_matches(&self, other: &Self) -> usize {
+        assert_eq!(self.len, other.len, "Sequences have different lengths.");
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(a, b)| nonzero_nibbles(a & b).count_ones() as usize)
+            .sum()
+    }
+}
+
+/// Sequences match if they have the same length, and the bases at every
+/// position match.
+impl Match<PackedSeq<DNA>> for PackedSeq<DNA> {
+    fn matches(&self, other: &PackedSeq<DNA>) -> bool {
+        self.len == other.len && self.zip_words(other, |a, b| a & b).is_some()
+    }
+}
+
+/// Position-wise set operations over whole words.
+///
+/// Each method panics if the sequences have different lengths.
+///
+/// # Examples:
+///
+/// ```
+/// use seqrs::alphabet::{RedundantAlphabet, DNA};
+/// use seqrs::packed::PackedSeq;
+///
+/// let a: PackedSeq<DNA> = "ACGT".parse().unwrap();
+/// let b: PackedSeq<DNA> = "AGGC".parse().unwrap();
+///
+/// assert_eq!(a.union(&b).to_string(), "ASGY");
+/// assert!(a.intersection(&b).is_none());
+/// assert!(a.union(&b).is_redundant());
+/// ```
+impl RedundantAlphabet for PackedSeq<DNA> {
+    fn union(&self, other: &Self) -> Self {
+        self.zip_words(other, |a, b| a | b)
+            .expect("Union of non-empty bases is never empty.")
+    }
+
+    fn intersection(&self, other: &Self) -> Option<Self> {
+        self.zip_words(other, |a, b| a & b)
+    }
+
+    fn difference(&self, other: &Self) -> Option<Self> {
+        self.zip_words(other, |a, b| a & !b)
+    }
+
+    /// Is any base in the sequence redundant?
+    fn is_redundant(&self) -> bool {
+        self.words.iter().any(|w| {
+            // Count the bits set in each nibble.
+            let pairs = (w & 0x5555_5555_5555_5555) + ((w >> 1) & 0x5555_5555_5555_5555);
+            let counts = (pairs & 0x3333_3333_3333_3333) + ((pairs >> 2) & 0x3333_3333_3333_3333);
+            counts & 0x6666_6666_6666_6666 != 0
+        })
+    }
+}
+
+/// A borrowed view of part of a [`PackedSeq`].
+///
+/// [`PackedSeq`]: struct.PackedSeq.html
+#[derive(Debug)]
+pub struct PackedSlice<'a, A> {
+    seq: &'a PackedSeq<A>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, A: PackedAlphabet> PackedSlice<'a, A> {
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn get(&self, index: usize) -> Option<A> {
+        if index < self.len() {
+            Some(self.seq.get_unchecked(self.start + index))
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> PackedIter<'a, A> {
+        PackedIter {
+            seq: self.seq,
+            start: self.start,
+            end: self.end,
+        }
+    }
+
+    /// A view of part of this slice, relative to the start of the slice.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> PackedSlice<'a, A> {
+        let (start, end) = resolve_range(range, self.len());
+        PackedSlice {
+            seq: self.seq,
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+
+    /// Copy the slice into a new sequence.
+    pub fn to_packed_seq(&self) -> PackedSeq<A> {
+        self.iter().collect()
+    }
+}
+
+impl<'a, A> Clone for PackedSlice<'a, A> {
+    fn clone(&self) -> Self {
+        PackedSlice {
+            seq: self.seq,
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<'a, A> Copy for PackedSlice<'a, A> {}
+
+impl<'a, A: PackedAlphabet> IntoIterator for PackedSlice<'a, A> {
+    type Item = A;
+    type IntoIter = PackedIter<'a, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, A> std::fmt::Display for PackedSlice<'a, A>
+where
+    A: PackedAlphabet,
+    for<'b> char: From<&'b A>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for b in self.iter() {
+            write!(f, "{}", char::from(&b))?;
+        }
+        Ok(())
+    }
+}
+
+/// An iterator over the letters in a packed sequence.
+#[derive(Debug)]
+pub struct PackedIter<'a, A> {
+    seq: &'a PackedSeq<A>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, A> Clone for PackedIter<'a, A> {
+    fn clone(&self) -> Self {
+        PackedIter {
+            seq: self.seq,
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<'a, A: PackedAlphabet> Iterator for PackedIter<'a, A> {
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            let base = self.seq.get_unchecked(self.start);
+            self.start += 1;
+            Some(base)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<'a, A: PackedAlphabet> DoubleEndedIterator for PackedIter<'a, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.end -= 1;
+            Some(self.seq.get_unchecked(self.end))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, A: PackedAlphabet> ExactSizeIterator for PackedIter<'a, A> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// Convert range bounds into a start and end, panicking if out of bounds.
+fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("Slice start {} out of bounds for length {}.", s, len)),
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("Slice end {} out of bounds for length {}.", e, len)),
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end,
+        "Slice starts at {} but ends at {}.",
+        start,
+        end
+    );
+    assert!(
+        end <= len,
+        "Slice end {} out of bounds for length {}.",
+        end,
+        len
+    );
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complement::IntoReverseComplement;
+    use proptest::collection::vec;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_word_boundaries() {
+        let bases: Vec<DNA4> = (0..70).map(|i| DNA4::from_rank(i % 4).unwrap()).collect();
+        let mut seq: PackedSeq<DNA4> = bases.iter().cloned().collect();
+        assert_eq!(seq.words().len(), 3);
+        assert_eq!(seq.get(64), Some(DNA4::A));
+        assert_eq!(seq.get(70), None);
+
+        seq.set(33, DNA4::T);
+        assert_eq!(seq.get(33), Some(DNA4::T));
+        assert_eq!(seq.get(32), Some(DNA4::A));
+        assert_eq!(seq.get(34), Some(DNA4::G));
+    }
+
+    #[test]
+    fn test_slicing() {
+        let seq: PackedSeq<DNA4> = "AACCGGTTAC".parse().unwrap();
+        let sl = seq.slice(2..8);
+        assert_eq!(sl.to_string(), "CCGGTT");
+        assert_eq!(sl.slice(1..=2).to_string(), "CG");
+        assert_eq!(sl.get(5), Some(DNA4::T));
+        assert_eq!(sl.get(6), None);
+        assert_eq!(seq.slice(8..).to_string(), "AC");
+        assert!(seq.slice(3..3).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_out_of_bounds() {
+        let seq: PackedSeq<DNA4> = "ACGT".parse().unwrap();
+        let _dummy = seq.slice(2..5);
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut seq: PackedSeq<DNA4> = std::iter::repeat(DNA4::T).take(100).collect();
+        seq.fill(30..70, DNA4::A);
+        seq.fill(..2, DNA4::C);
+        seq.fill(98.., DNA4::G);
+
+        for (i, b) in seq.iter().enumerate() {
+            let expected = match i {
+                0..=1 => DNA4::C,
+                30..=69 => DNA4::A,
+                98..=99 => DNA4::G,
+                _ => DNA4::T,
+            };
+            assert_eq!(b, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_inclusive_overflow() {
+        let seq: PackedSeq<DNA4> = "ACGT".parse().unwrap();
+        let _dummy = seq.slice(0..=usize::max_value());
+    }
+
+    proptest! {
+        #[test]
+        fn test_iter_recovers_original(bases in vec(select(DNA4::variants()), 0..200)) {
+            let seq: PackedSeq<DNA4> = bases.iter().cloned().collect();
+            assert_eq!(seq.len(), bases.len());
+            assert_eq!(seq.iter().collect::<Vec<DNA4>>(), bases.clone());
+
+            let rev: Vec<DNA4> = seq.iter().rev().collect();
+            let expected: Vec<DNA4> = bases.iter().rev().cloned().collect();
+            assert_eq!(rev, expected);
+        }
+
+        #[test]
+        fn test_dna_iter_recovers_original(bases in vec(select(DNA::variants()), 0..100)) {
+            let seq: PackedSeq<DNA> = bases.iter().cloned().collect();
+            assert_eq!(seq.words().len(), (bases.len() + 15) / 16);
+            assert_eq!(seq.iter().collect::<Vec<DNA>>(), bases);
+        }
+
+        #[test]
+        fn test_dna_reverse_complement(bases in vec(select(DNA::variants()), 0..100)) {
+            let seq: PackedSeq<DNA> = bases.iter().cloned().collect();
+            let expected: PackedSeq<DNA> = bases.iter().reverse_complement().collect();
+            assert_eq!(seq.reverse_complement(), expected);
+        }
+
+        // Word-wise set operations agree with the per-base ones.
+        #[test]
+        fn test_dna_set_operations(
+            pairs in vec((select(DNA::variants()), select(DNA::variants())), 0..100)
+        ) {
+            let a: PackedSeq<DNA> = pairs.iter().map(|p| p.0).collect();
+            let b: PackedSeq<DNA> = pairs.iter().map(|p| p.1).collect();
+
+            let matches = pairs.iter().filter(|(x, y)| x.matches(y)).count();
+            assert_eq!(a.count_matches(&b), matches);
+            assert_eq!(a.matches(&b), matches == pairs.len());
+
+            let union: PackedSeq<DNA> = pairs.iter().map(|(x, y)| x.union(y)).collect();
+            assert_eq!(a.union(&b), union);
+
+            let inter: Option<PackedSeq<DNA>> = pairs.iter().map(|(x, y)| x.intersection(y)).collect();
+            assert_eq!(a.intersection(&b), inter);
+
+            let diff: Option<PackedSeq<DNA>> = pairs.iter().map(|(x, y)| x.difference(y)).collect();
+            assert_eq!(a.difference(&b), diff);
+
+            assert_eq!(a.is_redundant(), pairs.iter().any(|p| p.0.is_redundant()));
+        }
+
+        // The word-wise reverse complement agrees with the iterator adapter.
+        #[test]
+        fn test_reverse_complement(bases in vec(select(DNA4::variants()), 0..200)) {
+            let seq: PackedSeq<DNA4> = bases.iter().cloned().collect();
+            let expected: PackedSeq<DNA4> = bases.iter().reverse_complement().collect();
+            assert_eq!(seq.reverse_complement(), expected);
+        }
+    }
+}
@@ -0,0 +1,435 @@
+//! Bulk parsing of ASCII sequences.
+//!
+//! These functions validate and encode whole sequences at once, using SIMD
+//! instructions where the CPU supports them. They are equivalent to calling
+//! `TryFrom<&u8>` on each byte, but report the offset of the first invalid
+//! byte in the `position` of the `AlphabetReadError`.
+//!
+//! On x86_64, `DNA4` and `AA` are parsed with AVX2 if it is available at
+//! runtime, and SSE2 otherwise. `DNA` needs a byte shuffle, so is parsed with
+//! AVX2 or the scalar fallback. Other platforms always use the scalar
+//! fallback.
+//!
+//! # Examples:
+//!
+//! ```
+//! use seqrs::alphabet::DNA4;
+//! use seqrs::errors::{Position, SeqErrorKind};
+//! use seqrs::parse::parse_dna4;
+//!
+//! assert_eq!(parse_dna4(b"ACgt").unwrap(), vec![DNA4::A, DNA4::C, DNA4::G, DNA4::T]);
+//!
+//! let err = parse_dna4(b"ACGTACGTACGTACGTACGTNACGT").unwrap_err();
+//! assert_eq!(
+//!     err.kind(),
+//!     &SeqErrorKind::AlphabetReadError { base: 'N', position: Position(Some(20)) }
+//! );
+//! ```
+
+use std::convert::TryFrom;
+
+use crate::alphabet::{AA, DNA, DNA4};
+use crate::errors::{Position, SeqError, SeqErrorKind};
+
+/// Parse and validate a sequence of `DNA4` bases.
+pub fn parse_dna4(bytes: &[u8]) -> Result<Vec<DNA4>, SeqError> {
+    unsafe { parse_with(bytes, encode_dna4) }
+}
+
+/// Parse and validate a sequence of `DNA` bases, including redundant bases.
+pub fn parse_dna(bytes: &[u8]) -> Result<Vec<DNA>, SeqError> {
+    unsafe { parse_with(bytes, encode_dna) }
+}
+
+/// Parse and validate a sequence of amino acids.
+pub fn parse_aa(bytes: &[u8]) -> Result<Vec<AA>, SeqError> {
+    unsafe { parse_with(bytes, encode_aa) }
+}
+
+/// An encoder writes the encoded bytes of `src` into `dst`, returning the
+/// offset of the first invalid byte if there is one.
+type Encoder = fn(&[u8], &mut [u8]) -> Result<(), usize>;
+
+/// Encode bytes, and reinterpret the result as a `Vec<A>`.
+///
+/// `A` must be a `repr(u8)` enum, and the encoder must only write valid
+/// discriminants of `A`.
+unsafe fn parse_with<A>(bytes: &[u8], encoder: Encoder) -> Result<Vec<A>, SeqError> {
+    debug_assert_eq!(std::mem::size_of::<A>(), 1);
+
+    let mut out = vec![0_u8; bytes.len()];
+    encoder(bytes, &mut out).map_err(|i| {
+        SeqError::from(SeqErrorKind::AlphabetReadError {
+            base: bytes[i].to_ascii_uppercase() as char,
+            position: Position(Some(i)),
+        })
+    })?;
+
+    let mut out = std::mem::ManuallyDrop::new(out);
+    Ok(Vec::from_raw_parts(
+        out.as_mut_ptr() as *mut A,
+        out.len(),
+        out.capacity(),
+    ))
+}
+
+fn encode_dna4(src: &[u8], dst: &mut [u8]) -> Result<(), usize> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { x86::encode_dna4_avx2(src, dst) };
+        }
+        return unsafe { x86::encode_dna4_sse2(src, dst) };
+    }
+
+    #[allow(unreachable_code)]
+    scalar::encode_dna4(src, dst, 0)
+}
+
+fn encode_dna(src: &[u8], dst: &mut [u8]) -> Result<(), usize> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { x86::encode_dna_avx2(src, dst) };
+        }
+    }
+
+    scalar::encode_dna(src, dst, 0)
+}
+
+fn encode_aa(src: &[u8], dst: &mut [u8]) -> Result<(), usize> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { x86::encode_aa_avx2(src, dst) };
+        }
+        return unsafe { x86::encode_aa_sse2(src, dst) };
+    }
+
+    #[allow(unreachable_code)]
+    scalar::encode_aa(src, dst, 0)
+}
+
+/// Byte at a time encoders, used for the ends of sequences that don't fill
+/// a whole SIMD register. `offset` is the position of `src` in the whole
+/// sequence, for error reporting.
+mod scalar {
+    use super::*;
+
+    /// `A`, `C`, `G`, and `T` (in either case) are encoded as their DNA4
+    /// ranks by `((b >> 1) ^ (b >> 2)) & 0b11`.
+    #[inline]
+    pub(super) fn dna4_rank(b: u8) -> u8 {
+        ((b >> 1) ^ (b >> 2)) & 0b11
+    }
+
+    pub(super) fn encode_dna4(src: &[u8], dst: &mut [u8], offset: usize) -> Result<(), usize> {
+        for (i, (s, d)) in src.iter().zip(dst.iter_mut()).enumerate() {
+            match s & 0xDF {
+                b'A' | b'C' | b'G' | b'T' => *d = dna4_rank(*s),
+                _ => return Err(offset + i),
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn encode_dna(src: &[u8], dst: &mut [u8], offset: usize) -> Result<(), usize> {
+        for (i, (s, d)) in src.iter().zip(dst.iter_mut()).enumerate() {
+            *d = DNA::try_from(s).map_err(|_| offset + i)? as u8;
+        }
+        Ok(())
+    }
+
+    pub(super) fn encode_aa(src: &[u8], dst: &mut [u8], offset: usize) -> Result<(), usize> {
+        for (i, (s, d)) in src.iter().zip(dst.iter_mut()).enumerate() {
+            let upper = s & 0xDF;
+            if !(b'A'..=b'Z').contains(&upper) {
+                return Err(offset + i);
+            }
+            // AA variants are the letters in alphabetical order.
+            *d = upper - b'A';
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use super::scalar;
+    use std::arch::x86_64::*;
+
+    // The DNA letters with high nibble 4 (`@` to `O`) and 5 (`P` to `_`),
+    // indexed by the low nibble. Invalid letters are 0.
+    #[rustfmt::skip]
+    const DNA_HIGH_4: [i8; 16] = [
+        0, 0b0001, 0b1110, 0b0010, 0b1101, 0, 0, 0b0100,
+        0b1011, 0, 0, 0b1100, 0, 0b0011, 0b1111, 0,
+    ];
+
+    #[rustfmt::skip]
+    const DNA_HIGH_5: [i8; 16] = [
+        0, 0, 0b0101, 0b0110, 0b1000, 0, 0b0111, 0b1001,
+        0, 0b1010, 0, 0, 0, 0, 0, 0,
+    ];
+
+    /// Run a SIMD kernel over each whole chunk, then the scalar encoder over
+    /// the remainder.
+    ///
+    /// The kernel evaluates to a bitmask with bits set for invalid bytes.
+    /// This is a macro rather than a function taking a closure, so that the
+    /// kernel is always compiled with the caller's target features.
+    macro_rules! chunked {
+        ($src:expr, $dst:expr, $width:expr, $tail:path, |$s:ident, $d:ident| $kernel:block) => {{
+            let (src, dst): (&[u8], &mut [u8]) = ($src, $dst);
+            let whole = src.len() - src.len() % $width;
+            let mut i = 0;
+            while i < whole {
+                let $s = src.as_ptr().add(i);
+                let $d = dst.as_mut_ptr().add(i);
+                let invalid: u32 = $kernel;
+                if invalid != 0 {
+                    return Err(i + invalid.trailing_zeros() as usize);
+                }
+                i += $width;
+            }
+
+            $tail(&src[whole..], &mut dst[whole..], whole)
+        }};
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn encode_dna4_sse2(src: &[u8], dst: &mut [u8]) -> Result<(), usize> {
+        chunked!(src, dst, 16, scalar::encode_dna4, |s, d| {
+            let v = _mm_loadu_si128(s as *const __m128i);
+            let upper = _mm_and_si128(v, _mm_set1_epi8(0xDF_u8 as i8));
+            let valid = _mm_or_si128(
+                _mm_or_si128(
+                    _mm_cmpeq_epi8(upper, _mm_set1_epi8(b'A' as i8)),
+                    _mm_cmpeq_epi8(upper, _mm_set1_epi8(b'C' as i8)),
+                ),
+                _mm_or_si128(
+                    _mm_cmpeq_epi8(upper, _mm_set1_epi8(b'G' as i8)),
+                    _mm_cmpeq_epi8(upper, _mm_set1_epi8(b'T' as i8)),
+                ),
+            );
+
+            // Bits shifted in from neighbouring bytes are masked off.
+            let ranks = _mm_and_si128(
+                _mm_xor_si128(_mm_srli_epi16(v, 1), _mm_srli_epi16(v, 2)),
+                _mm_set1_epi8(0b11),
+            );
+            _mm_storeu_si128(d as *mut __m128i, ranks);
+            !(_mm_movemask_epi8(valid) as u32) & 0xFFFF
+        })
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn encode_dna4_avx2(src: &[u8], dst: &mut [u8]) -> Result<(), usize> {
+        chunked!(src, dst, 32, scalar::encode_dna4, |s, d| {
+            let v = _mm256_loadu_si256(s as *const __m256i);
+            let upper = _mm256_and_si256(v, _mm256_set1_epi8(0xDF_u8 as i8));
+            let valid = _mm256_or_si256(
+                _mm256_or_si256(
+                    _mm256_cmpeq_epi8(upper, _mm256_set1_epi8(b'A' as i8)),
+                    _mm256_cmpeq_epi8(upper, _mm256_set1_epi8(b'C' as i8)),
+                ),
+                _mm256_or_si256(
+                    _mm256_cmpeq_epi8(upper, _mm256_set1_epi8(b'G' as i8)),
+                    _mm256_cmpeq_epi8(upper, _mm256_set1_epi8(b'T' as i8)),
+                ),
+            );
+
+            let ranks = _mm256_and_si256(
+                _mm256_xor_si256(_mm256_srli_epi16(v, 1), _mm256_srli_epi16(v, 2)),
+                _mm256_set1_epi8(0b11),
+            );
+            _mm256_storeu_si256(d as *mut __m256i, ranks);
+            !(_mm256_movemask_epi8(valid) as u32)
+        })
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn encode_dna_avx2(src: &[u8], dst: &mut [u8]) -> Result<(), usize> {
+        let high4 =
+            _mm256_broadcastsi128_si256(_mm_loadu_si128(DNA_HIGH_4.as_ptr() as *const __m128i));
+        let high5 =
+            _mm256_broadcastsi128_si256(_mm_loadu_si128(DNA_HIGH_5.as_ptr() as *const __m128i));
+
+        chunked!(src, dst, 32, scalar::encode_dna, |s, d| {
+            let v = _mm256_loadu_si256(s as *const __m256i);
+            let upper = _mm256_and_si256(v, _mm256_set1_epi8(0xDF_u8 as i8));
+            let low = _mm256_and_si256(upper, _mm256_set1_epi8(0x0F));
+            let high = _mm256_and_si256(_mm256_srli_epi16(upper, 4), _mm256_set1_epi8(0x0F));
+
+            let codes = _mm256_or_si256(
+                _mm256_and_si256(
+                    _mm256_shuffle_epi8(high4, low),
+                    _mm256_cmpeq_epi8(high, _mm256_set1_epi8(4)),
+                ),
+                _mm256_and_si256(
+                    _mm256_shuffle_epi8(high5, low),
+                    _mm256_cmpeq_epi8(high, _mm256_set1_epi8(5)),
+                ),
+            );
+
+            _mm256_storeu_si256(d as *mut __m256i, codes);
+            let invalid = _mm256_cmpeq_epi8(codes, _mm256_setzero_si256());
+            _mm256_movemask_epi8(invalid) as u32
+        })
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn encode_aa_sse2(src: &[u8], dst: &mut [u8]) -> Result<(), usize> {
+        chunked!(src, dst, 16, scalar::encode_aa, |s, d| {
+            let v = _mm_loadu_si128(s as *const __m128i);
+            let upper = _mm_and_si128(v, _mm_set1_epi8(0xDF_u8 as i8));
+
+            // Bytes above 0x7F are negative, so fail the first compare.
+            let valid = _mm_and_si128(
+                _mm_cmpgt_epi8(upper, _mm_set1_epi8(b'A' as i8 - 1)),
+                _mm_cmplt_epi8(upper, _mm_set1_epi8(b'Z' as i8 + 1)),
+            );
+
+            _mm_storeu_si128(
+                d as *mut __m128i,
+                _mm_sub_epi8(upper, _mm_set1_epi8(b'A' as i8)),
+            );
+            !(_mm_movemask_epi8(valid) as u32) & 0xFFFF
+        })
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn encode_aa_avx2(src: &[u8], dst: &mut [u8]) -> Result<(), usize> {
+        chunked!(src, dst, 32, scalar::encode_aa, |s, d| {
+            let v = _mm256_loadu_si256(s as *const __m256i);
+            let upper = _mm256_and_si256(v, _mm256_set1_epi8(0xDF_u8 as i8));
+
+            let valid = _mm256_and_si256(
+                _mm256_cmpgt_epi8(upper, _mm256_set1_epi8(b'A' as i8 - 1)),
+                _mm256_cmpgt_epi8(_mm256_set1_epi8(b'Z' as i8 + 1), upper),
+            );
+
+            _mm256_storeu_si256(
+                d as *mut __m256i,
+                _mm256_sub_epi8(upper, _mm256_set1_epi8(b'A' as i8)),
+            );
+            !(_mm256_movemask_epi8(valid) as u32)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    /// Parse byte by byte with `TryFrom`, for comparison.
+    fn parse_slow<A>(bytes: &[u8]) -> Result<Vec<A>, SeqErrorKind>
+    where
+        A: for<'a> TryFrom<&'a u8, Error = SeqError>,
+    {
+        let mut out = Vec::new();
+        for (i, b) in bytes.iter().enumerate() {
+            out.push(A::try_from(b).map_err(|e| e.at_position(i).kind().clone())?);
+        }
+        Ok(out)
+    }
+
+    fn position(err: SeqError) -> Option<usize> {
+        match err.kind() {
+            SeqErrorKind::AlphabetReadError { position, .. } => position.0,
+            _ => None,
+        }
+    }
+
+    // Mostly valid letters, with occasional junk to exercise the error path.
+    fn letters() -> Vec<u8> {
+        let mut letters: Vec<u8> = (b'A'..=b'Z').chain(b'a'..=b'z').collect();
+        letters.extend_from_slice(b"ACGTACGTacgtNn-*@[`{\x00\xC1\xFF");
+        letters
+    }
+
+    #[test]
+    fn test_error_position() {
+        let mut seq = vec![b'A'; 100];
+        seq[70] = b'J';
+        let err = parse_dna(&seq).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &SeqErrorKind::AlphabetReadError {
+                base: 'J',
+                position: Position(Some(70))
+            }
+        );
+
+        assert_eq!(position(parse_aa(b"ACDEF1").unwrap_err()), Some(5));
+
+        // Letters are reported in upper case, like `TryFrom`.
+        let err = parse_dna4(b"ACgn").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &SeqErrorKind::AlphabetReadError {
+                base: 'N',
+                position: Position(Some(3))
+            }
+        );
+        assert_eq!(parse_aa(b"").unwrap(), vec![]);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_sse2_agrees_with_scalar() {
+        let seqs: Vec<&[u8]> = vec![
+            b"ACGTACGTACGTACGTACGTACGTACGTACGTACG",
+            b"ACGTACGTACGTACGTACGTAXGTACGTACGTACG",
+            b"acgtacgtacgtacgtACGTACGTACGTACGTACGT",
+            b"ACGTACGTACG\xC3ACGTACGTACGTACGT",
+        ];
+
+        for s in seqs {
+            let mut a = vec![0; s.len()];
+            let mut b = vec![0; s.len()];
+
+            let simd = unsafe { x86::encode_dna4_sse2(s, &mut a) };
+            let slow = scalar::encode_dna4(s, &mut b, 0);
+            assert_eq!(simd, slow);
+            if slow.is_ok() {
+                assert_eq!(a, b);
+            }
+
+            let simd = unsafe { x86::encode_aa_sse2(s, &mut a) };
+            let slow = scalar::encode_aa(s, &mut b, 0);
+            assert_eq!(simd, slow);
+            if slow.is_ok() {
+                assert_eq!(a, b);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_dna4_agrees_with_try_from(s in vec(select(letters()), 0..100)) {
+            let fast = parse_dna4(&s).map_err(|e| e.kind().clone());
+            assert_eq!(fast, parse_slow::<DNA4>(&s));
+        }
+
+        #[test]
+        fn test_dna_agrees_with_try_from(s in vec(select(letters()), 0..100)) {
+            let fast = parse_dna(&s).map_err(|e| e.kind().clone());
+            assert_eq!(fast, parse_slow::<DNA>(&s));
+        }
+
+        #[test]
+        fn test_aa_agrees_with_try_from(s in vec(select(letters()), 0..100)) {
+            let fast = parse_aa(&s).map_err(|e| e.kind().clone());
+            assert_eq!(fast, parse_slow::<AA>(&s));
+        }
+
+        #[test]
+        fn test_valid_dna4_parses(s in vec(select(b"ACGTacgt".to_vec()), 0..100)) {
+            assert_eq!(parse_dna4(&s).unwrap(), parse_slow::<DNA4>(&s).unwrap());
+        }
+    }
+}
@@ -0,0 +1,396 @@
+//! Phred base qualities, and sequences with qualities attached.
+//!
+//! Qualities are read from and written to ASCII with either the
+//! Sanger/Illumina 1.8+ offset of 33, or the legacy Illumina 1.3-1.7 offset
+//! of 64.
+//!
+//! # Examples:
+//!
+//! ```
+//! use seqrs::alphabet::DNA;
+//! use seqrs::quality::{PhredEncoding, QualSeq};
+//!
+//! let read: QualSeq<DNA> = QualSeq::from_ascii(b"ACGT", b"II#5", PhredEncoding::Phred33).unwrap();
+//! assert_eq!(read.qual()[2].score(), 2);
+//!
+//! // Reverse complementing also reverses the qualities.
+//! let rc = read.reverse_complement();
+//! assert_eq!(rc.seq(), &[DNA::A, DNA::C, DNA::G, DNA::T]);
+//! assert_eq!(rc.qual_to_ascii(PhredEncoding::Phred33), b"5#II".to_vec());
+//! ```
+
+use std::convert::TryFrom;
+use std::iter::FromIterator;
+
+use crate::alphabet::Alphabet;
+use crate::complement::{Complement, IntoReverseComplement};
+use crate::errors::{SeqError, SeqErrorKind};
+
+/// The ASCII offsets used to encode Phred scores.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum PhredEncoding {
+    /// Sanger and Illumina 1.8+ (`!` is 0).
+    Phred33,
+    /// Illumina 1.3 to 1.7 (`@` is 0).
+    Phred64,
+}
+
+impl PhredEncoding {
+    /// The ASCII value representing a score of 0.
+    pub fn offset(&self) -> u8 {
+        match self {
+            PhredEncoding::Phred33 => 33,
+            PhredEncoding::Phred64 => 64,
+        }
+    }
+}
+
+/// A Phred quality score, from 0 to 93.
+///
+/// The score `Q` relates to the probability `P` that a base call is wrong by
+/// `Q = -10 log10(P)`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Phred(u8);
+
+impl Phred {
+    /// The largest score that can be written as printable ASCII.
+    pub const MAX: u8 = 93;
+
+    /// The quality score.
+    #[inline]
+    pub fn score(&self) -> u8 {
+        self.0
+    }
+
+    /// Decode an ASCII quality character.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::quality::{Phred, PhredEncoding};
+    ///
+    /// let q = Phred::from_ascii(b'I', PhredEncoding::Phred33).unwrap();
+    /// assert_eq!(q.score(), 40);
+    ///
+    /// let q = Phred::from_ascii(b'h', PhredEncoding::Phred64).unwrap();
+    /// assert_eq!(q.score(), 40);
+    ///
+    /// assert!(Phred::from_ascii(b'5', PhredEncoding::Phred64).is_err());
+    /// ```
+    pub fn from_ascii(qual: u8, encoding: PhredEncoding) -> Result<Self, SeqError> {
+        Some(qual)
+            .filter(|&q| q <= b'~')
+            .and_then(|q| q.checked_sub(encoding.offset()))
+            .and_then(|q| Phred::from_rank(q as usize))
+            .ok_or_else(|| SeqErrorKind::QualityReadError { qual: qual as char }.into())
+    }
+
+    /// Encode the score as an ASCII quality character.
+    ///
+    /// Scores too large to be written with an offset of 64 are written as
+    /// the largest printable score (`~`).
+    pub fn to_ascii(&self, encoding: PhredEncoding) -> u8 {
+        self.0.saturating_add(encoding.offset()).min(b'~')
+    }
+
+    /// The probability that the base call is wrong.
+    #[inline]
+    pub fn error_probability(&self) -> f64 {
+        10_f64.powf(-(self.0 as f64) / 10.0)
+    }
+
+    /// The nearest score to an error probability.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::quality::Phred;
+    ///
+    /// assert_eq!(Phred::from_error_probability(0.001).score(), 30);
+    /// assert_eq!(Phred::from_error_probability(0.0).score(), 93);
+    /// ```
+    pub fn from_error_probability(p: f64) -> Self {
+        let q = (-10.0 * p.log10()).round();
+        if q.is_nan() || q <= 0.0 {
+            Phred(0)
+        } else {
+            Phred(q.min(Self::MAX as f64) as u8)
+        }
+    }
+}
+
+impl Alphabet for Phred {
+    /// The number of scores that can be written as printable ASCII.
+    fn cardinality() -> usize {
+        Self::MAX as usize + 1
+    }
+
+    fn rank(&self) -> usize {
+        self.0 as usize
+    }
+
+    unsafe fn from_rank_unsafe(r: usize) -> Self {
+        debug_assert!(r < Self::cardinality());
+        Phred(r as u8)
+    }
+
+    /// Returns a Vec of all of the scores.
+    fn variants() -> Vec<Self> {
+        (0..=Self::MAX).map(Phred).collect()
+    }
+}
+
+/// A base with an attached quality score.
+///
+/// Complementing a qualified base complements the base and keeps the
+/// quality, so reverse complementing a sequence of qualified bases also
+/// reverses the qualities.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Qualified<T> {
+    pub base: T,
+    pub qual: Phred,
+}
+
+impl<T> Qualified<T> {
+    pub fn new(base: T, qual: Phred) -> Self {
+        Qualified {
+            base: base,
+            qual: qual,
+        }
+    }
+}
+
+impl<T> Complement for Qualified<T>
+where
+    T: Complement,
+{
+    type Compl = Qualified<<T as Complement>::Compl>;
+
+    fn complement(&self) -> Self::Compl {
+        Qualified::new(self.base.complement(), self.qual)
+    }
+}
+
+/// A sequence with a quality score for each base, e.g. a FASTQ record.
+///
+/// Bases can be any type, including [`Gapped`] bases.
+///
+/// [`Gapped`]: ../gapped/enum.Gapped.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QualSeq<A> {
+    seq: Vec<A>,
+    qual: Vec<Phred>,
+}
+
+impl<A> QualSeq<A> {
+    /// Attach qualities to a sequence, failing if they have different
+    /// lengths.
+    pub fn new(seq: Vec<A>, qual: Vec<Phred>) -> Result<Self, SeqError> {
+        if seq.len() != qual.len() {
+            return Err(SeqErrorKind::QualityLengthError {
+                seq: seq.len(),
+                qual: qual.len(),
+            }
+            .into());
+        }
+
+        Ok(QualSeq {
+            seq: seq,
+            qual: qual,
+        })
+    }
+
+    /// Parse a sequence and its ASCII encoded qualities.
+    pub fn from_ascii(seq: &[u8], qual: &[u8], encoding: PhredEncoding) -> Result<Self, SeqError>
+    where
+        A: for<'a> TryFrom<&'a u8, Error = SeqError>,
+    {
+        let seq: Result<Vec<A>, SeqError> = seq
+            .iter()
+            .enumerate()
+            .map(|(i, b)| A::try_from(b).map_err(|e| e.at_position(i)))
+            .collect();
+        let qual: Result<Vec<Phred>, SeqError> = qual
+            .iter()
+            .map(|q| Phred::from_ascii(*q, encoding))
+            .collect();
+        Self::new(seq?, qual?)
+    }
+
+    pub fn seq(&self) -> &[A] {
+        &self.seq
+    }
+
+    pub fn qual(&self) -> &[Phred] {
+        &self.qual
+    }
+
+    pub fn len(&self) -> usize {
+        self.seq.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.seq.is_empty()
+    }
+
+    /// Separate the sequence and qualities.
+    pub fn into_parts(self) -> (Vec<A>, Vec<Phred>) {
+        (self.seq, self.qual)
+    }
+
+    /// Iterate over the bases with their qualities.
+    pub fn iter<'a>(&'a self) -> impl DoubleEndedIterator<Item = Qualified<&'a A>> + 'a {
+        self.seq
+            .iter()
+            .zip(self.qual.iter())
+            .map(|(b, q)| Qualified::new(b, *q))
+    }
+
+    /// Encode the qualities as ASCII.
+    pub fn qual_to_ascii(&self, encoding: PhredEncoding) -> Vec<u8> {
+        self.qual.iter().map(|q| q.to_ascii(encoding)).collect()
+    }
+
+    /// The expected number of incorrect base calls.
+    pub fn expected_errors(&self) -> f64 {
+        self.qual.iter().map(|q| q.error_probability()).sum()
+    }
+
+    /// The reverse complement of the sequence, with the qualities reversed.
+    pub fn reverse_complement<'a>(&'a self) -> QualSeq<<&'a A as Complement>::Compl>
+    where
+        &'a A: Complement,
+    {
+        self.iter()
+            .reverse_complement()
+            .map(|q| Qualified::new(q.base, q.qual))
+            .collect()
+    }
+}
+
+impl<A> FromIterator<Qualified<A>> for QualSeq<A> {
+    fn from_iter<I: IntoIterator<Item = Qualified<A>>>(iter: I) -> Self {
+        let (seq, qual) = iter.into_iter().map(|q| (q.base, q.qual)).unzip();
+        QualSeq {
+            seq: seq,
+            qual: qual,
+        }
+    }
+}
+
+impl<A> IntoIterator for QualSeq<A> {
+    type Item = Qualified<A>;
+    type IntoIter = std::iter::Map<
+        std::iter::Zip<std::vec::IntoIter<A>, std::vec::IntoIter<Phred>>,
+        fn((A, Phred)) -> Qualified<A>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        fn join<A>((base, qual): (A, Phred)) -> Qualified<A> {
+            Qualified::new(base, qual)
+        }
+
+        self.seq.into_iter().zip(self.qual.into_iter()).map(join)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::{DNA, DNA4};
+    use crate::gapped::Gapped;
+    use proptest::collection::vec;
+    use proptest::prelude::any;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_encodings() {
+        let q = Phred::from_ascii(b'!', PhredEncoding::Phred33).unwrap();
+        assert_eq!(q.score(), 0);
+        assert_eq!(q.to_ascii(PhredEncoding::Phred64), b'@');
+        assert!(Phred::from_ascii(b' ', PhredEncoding::Phred33).is_err());
+        assert!(Phred::from_ascii(127, PhredEncoding::Phred33).is_err());
+        assert!(Phred::from_ascii(0x80, PhredEncoding::Phred64).is_err());
+
+        let q = Phred::from_rank(70).unwrap();
+        assert_eq!(q.to_ascii(PhredEncoding::Phred64), b'~');
+    }
+
+    #[test]
+    fn test_error_probability() {
+        let q = Phred::from_rank(20).unwrap();
+        assert!((q.error_probability() - 0.01).abs() < 1e-12);
+        assert_eq!(Phred::from_error_probability(1.0).score(), 0);
+        assert_eq!(Phred::from_error_probability(2.0).score(), 0);
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        let err = QualSeq::<DNA>::from_ascii(b"ACG", b"II", PhredEncoding::Phred33).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &SeqErrorKind::QualityLengthError { seq: 3, qual: 2 }
+        );
+    }
+
+    #[test]
+    fn test_gapped_reverse_complement() {
+        let read: QualSeq<Gapped<DNA4>> =
+            QualSeq::from_ascii(b"AC-T", b"#+!I", PhredEncoding::Phred33).unwrap();
+        let rc = read.reverse_complement();
+
+        assert_eq!(
+            rc.seq(),
+            &[
+                Gapped::Base(DNA4::A),
+                Gapped::Gap,
+                Gapped::Base(DNA4::G),
+                Gapped::Base(DNA4::T)
+            ]
+        );
+        assert_eq!(rc.qual_to_ascii(PhredEncoding::Phred33), b"I!+#".to_vec());
+    }
+
+    proptest! {
+        #[test]
+        fn test_from_ascii_doesnt_crash(c in any::<u8>()) {
+            let _dummy = Phred::from_ascii(c, PhredEncoding::Phred33);
+            let _dummy = Phred::from_ascii(c, PhredEncoding::Phred64);
+        }
+
+        #[test]
+        fn test_ascii_recovers_original(q in select(Phred::variants())) {
+            let enc = PhredEncoding::Phred33;
+            assert_eq!(Phred::from_ascii(q.to_ascii(enc), enc).unwrap(), q);
+
+            if q.score() <= 62 {
+                let enc = PhredEncoding::Phred64;
+                assert_eq!(Phred::from_ascii(q.to_ascii(enc), enc).unwrap(), q);
+            }
+        }
+
+        #[test]
+        fn test_probability_recovers_original(q in select(Phred::variants())) {
+            assert_eq!(Phred::from_error_probability(q.error_probability()), q);
+        }
+
+        // Reverse complementing twice recovers the original record.
+        #[test]
+        fn test_reverse_complement_twice(
+            seq in vec(select(DNA::variants()), 0..30),
+            quals in vec(0_u8..=Phred::MAX, 30),
+        ) {
+            let qual: Vec<Phred> = quals[..seq.len()]
+                .iter()
+                .map(|&q| Phred::from_rank(q as usize).unwrap())
+                .collect();
+            let read = QualSeq::new(seq, qual).unwrap();
+            let rc = read.reverse_complement();
+
+            let rev: Vec<Phred> = read.qual().iter().rev().cloned().collect();
+            assert_eq!(rc.qual(), &rev[..]);
+            assert_eq!(rc.reverse_complement(), read);
+        }
+    }
+}
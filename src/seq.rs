@@ -0,0 +1,509 @@
+//! Owned and borrowed sequence containers.
+//!
+//! [`Seq`] is an owned, growable sequence of alphabet letters, and
+//! [`SeqSlice`] is a borrowed view of part of one, in the same way that
+//! `String` and `str` are related.
+//!
+//! # Examples:
+//!
+//! ```
+//! use seqrs::alphabet::{AA, DNA};
+//! use seqrs::seq::Seq;
+//! use seqrs::stopped::Stopped;
+//! use seqrs::translate::NCBITransTable;
+//!
+//! let mut seq: Seq<DNA> = "ATGTTTTAA".parse().unwrap();
+//! assert_eq!(seq[3..6].to_string(), "TTT");
+//!
+//! let protein: Vec<Stopped<AA>> = seq.translate(NCBITransTable::Standard).collect();
+//! assert_eq!(protein, vec![Stopped::Res(AA::M), Stopped::Res(AA::F), Stopped::Stop]);
+//!
+//! seq.reverse_complement_in_place();
+//! assert_eq!(seq.to_string(), "TTAAAACAT");
+//! ```
+//!
+//! [`Seq`]: struct.Seq.html
+//! [`SeqSlice`]: struct.SeqSlice.html
+
+use std::borrow::{Borrow, BorrowMut};
+use std::convert::TryFrom;
+use std::iter::{Cloned, FromIterator};
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use std::str::FromStr;
+
+use crate::codon::{Codons, IntoCodons};
+use crate::complement::{Complement, IntoReverseComplement, ReverseComplement};
+use crate::errors::SeqError;
+use crate::translate::{IntoTranslate, Translate, TranslationTable};
+
+/// An owned sequence, with an optional name.
+///
+/// The name isn't used when comparing or hashing sequences, so that a `Seq`
+/// behaves the same as the [`SeqSlice`] it borrows as.
+///
+/// [`SeqSlice`]: struct.SeqSlice.html
+#[derive(Debug, Clone, Default)]
+pub struct Seq<A> {
+    name: String,
+    seq: Vec<A>,
+}
+
+impl<A> Seq<A> {
+    pub fn new() -> Self {
+        Seq {
+            name: String::new(),
+            seq: Vec::new(),
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Seq {
+            name: String::new(),
+            seq: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Create a sequence with a name, e.g. from a FASTA header.
+    pub fn with_name<S: Into<String>>(name: S, seq: Vec<A>) -> Self {
+        Seq {
+            name: name.into(),
+            seq: seq,
+        }
+    }
+
+    /// The name of the sequence, which is empty if it wasn't given one.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set_name<S: Into<String>>(&mut self, name: S) {
+        self.name = name.into();
+    }
+
+    /// Parse a sequence from bytes, failing at the first invalid letter.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SeqError>
+    where
+        A: for<'a> TryFrom<&'a u8, Error = SeqError>,
+    {
+        bytes
+            .iter()
+            .enumerate()
+            .map(|(i, b)| A::try_from(b).map_err(|e| e.at_position(i)))
+            .collect()
+    }
+
+    pub fn push(&mut self, base: A) {
+        self.seq.push(base);
+    }
+
+    pub fn pop(&mut self) -> Option<A> {
+        self.seq.pop()
+    }
+
+    pub fn as_seq_slice(&self) -> &SeqSlice<A> {
+        SeqSlice::new(&self.seq)
+    }
+
+    pub fn as_mut_seq_slice(&mut self) -> &mut SeqSlice<A> {
+        SeqSlice::new_mut(&mut self.seq)
+    }
+
+    pub fn into_inner(self) -> Vec<A> {
+        self.seq
+    }
+}
+
+/// A borrowed view of a sequence.
+///
+/// This is an unsized type, and is always used behind a reference.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SeqSlice<A> {
+    seq: [A],
+}
+
+impl<A> SeqSlice<A> {
+    /// View a slice as a sequence.
+    pub fn new(seq: &[A]) -> &Self {
+        // SeqSlice is a transparent wrapper, so has the same layout as [A].
+        unsafe { &*(seq as *const [A] as *const SeqSlice<A>) }
+    }
+
+    pub fn new_mut(seq: &mut [A]) -> &mut Self {
+        unsafe { &mut *(seq as *mut [A] as *mut SeqSlice<A>) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.seq.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.seq.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[A] {
+        &self.seq
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [A] {
+        &mut self.seq
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, A> {
+        self.seq.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, A> {
+        self.seq.iter_mut()
+    }
+
+    pub fn to_seq(&self) -> Seq<A>
+    where
+        A: Clone,
+    {
+        Seq {
+            name: String::new(),
+            seq: self.seq.to_vec(),
+        }
+    }
+
+    /// Iterate over the codons in the first frame, ignoring incomplete
+    /// codons at the end.
+    pub fn codons(&self) -> Codons<std::slice::Iter<'_, A>> {
+        self.seq.iter().codons()
+    }
+
+    /// Translate the codons in the first frame.
+    pub fn translate<T, O>(
+        &self,
+        table: T,
+    ) -> Translate<Codons<Cloned<std::slice::Iter<'_, A>>>, T, O>
+    where
+        A: Clone,
+        T: TranslationTable<crate::codon::Codon<A>, O>,
+    {
+        self.seq.iter().cloned().codons().translate(table)
+    }
+
+    /// Iterate over the reverse complement of the sequence.
+    ///
+    /// Collect into a `Seq` to get an owned copy.
+    pub fn reverse_complement<'a>(&'a self) -> ReverseComplement<std::slice::Iter<'a, A>>
+    where
+        &'a A: Complement,
+    {
+        self.seq.iter().reverse_complement()
+    }
+
+    /// Reverse complement the sequence without allocating.
+    pub fn reverse_complement_in_place(&mut self)
+    where
+        A: Complement<Compl = A>,
+    {
+        self.seq.reverse();
+        for b in self.seq.iter_mut() {
+            *b = b.complement();
+        }
+    }
+}
+
+impl<A: PartialEq> PartialEq for Seq<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.seq == other.seq
+    }
+}
+
+impl<A: Eq> Eq for Seq<A> {}
+
+impl<A: PartialOrd> PartialOrd for Seq<A> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.seq.partial_cmp(&other.seq)
+    }
+}
+
+impl<A: Ord> Ord for Seq<A> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.seq.cmp(&other.seq)
+    }
+}
+
+impl<A: std::hash::Hash> std::hash::Hash for Seq<A> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.seq.hash(state)
+    }
+}
+
+impl<A> Deref for Seq<A> {
+    type Target = SeqSlice<A>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_seq_slice()
+    }
+}
+
+impl<A> DerefMut for Seq<A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_seq_slice()
+    }
+}
+
+impl<A> Borrow<SeqSlice<A>> for Seq<A> {
+    fn borrow(&self) -> &SeqSlice<A> {
+        self.as_seq_slice()
+    }
+}
+
+impl<A> BorrowMut<SeqSlice<A>> for Seq<A> {
+    fn borrow_mut(&mut self) -> &mut SeqSlice<A> {
+        self.as_mut_seq_slice()
+    }
+}
+
+impl<A: Clone> ToOwned for SeqSlice<A> {
+    type Owned = Seq<A>;
+
+    fn to_owned(&self) -> Seq<A> {
+        self.to_seq()
+    }
+}
+
+impl<A> AsRef<[A]> for SeqSlice<A> {
+    fn as_ref(&self) -> &[A] {
+        &self.seq
+    }
+}
+
+impl<A> AsRef<[A]> for Seq<A> {
+    fn as_ref(&self) -> &[A] {
+        &self.seq
+    }
+}
+
+impl<A> Index<usize> for SeqSlice<A> {
+    type Output = A;
+
+    fn index(&self, index: usize) -> &A {
+        &self.seq[index]
+    }
+}
+
+impl<A> IndexMut<usize> for SeqSlice<A> {
+    fn index_mut(&mut self, index: usize) -> &mut A {
+        &mut self.seq[index]
+    }
+}
+
+macro_rules! index_range {
+    ($($range:ty),*) => {
+        $(
+            impl<A> Index<$range> for SeqSlice<A> {
+                type Output = SeqSlice<A>;
+
+                fn index(&self, index: $range) -> &SeqSlice<A> {
+                    SeqSlice::new(&self.seq[index])
+                }
+            }
+
+            impl<A> IndexMut<$range> for SeqSlice<A> {
+                fn index_mut(&mut self, index: $range) -> &mut SeqSlice<A> {
+                    SeqSlice::new_mut(&mut self.seq[index])
+                }
+            }
+
+            impl<A> Index<$range> for Seq<A> {
+                type Output = SeqSlice<A>;
+
+                fn index(&self, index: $range) -> &SeqSlice<A> {
+                    &self.as_seq_slice()[index]
+                }
+            }
+
+            impl<A> IndexMut<$range> for Seq<A> {
+                fn index_mut(&mut self, index: $range) -> &mut SeqSlice<A> {
+                    &mut self.as_mut_seq_slice()[index]
+                }
+            }
+        )*
+    };
+}
+
+index_range!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>
+);
+
+impl<A> Index<usize> for Seq<A> {
+    type Output = A;
+
+    fn index(&self, index: usize) -> &A {
+        &self.seq[index]
+    }
+}
+
+impl<A> IndexMut<usize> for Seq<A> {
+    fn index_mut(&mut self, index: usize) -> &mut A {
+        &mut self.seq[index]
+    }
+}
+
+impl<A> From<Vec<A>> for Seq<A> {
+    fn from(seq: Vec<A>) -> Self {
+        Seq {
+            name: String::new(),
+            seq: seq,
+        }
+    }
+}
+
+impl<A> FromIterator<A> for Seq<A> {
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        Seq {
+            name: String::new(),
+            seq: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<A> Extend<A> for Seq<A> {
+    fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+        self.seq.extend(iter)
+    }
+}
+
+impl<A> IntoIterator for Seq<A> {
+    type Item = A;
+    type IntoIter = std::vec::IntoIter<A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.seq.into_iter()
+    }
+}
+
+impl<'a, A> IntoIterator for &'a Seq<A> {
+    type Item = &'a A;
+    type IntoIter = std::slice::Iter<'a, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.seq.iter()
+    }
+}
+
+impl<'a, A> IntoIterator for &'a SeqSlice<A> {
+    type Item = &'a A;
+    type IntoIter = std::slice::Iter<'a, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.seq.iter()
+    }
+}
+
+impl<A> FromStr for Seq<A>
+where
+    A: for<'a> TryFrom<&'a u8, Error = SeqError>,
+{
+    type Err = SeqError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl<A> std::fmt::Display for SeqSlice<A>
+where
+    for<'a> char: From<&'a A>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for b in self.seq.iter() {
+            write!(f, "{}", char::from(b))?;
+        }
+        Ok(())
+    }
+}
+
+impl<A> std::fmt::Display for Seq<A>
+where
+    for<'a> char: From<&'a A>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_seq_slice().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::{Alphabet, DNA, DNA4};
+    use crate::errors::{Position, SeqErrorKind};
+    use crate::gapped::Gapped;
+    use proptest::collection::vec;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_from_str() {
+        let seq: Seq<DNA4> = "acgT".parse().unwrap();
+        assert_eq!(seq.as_slice(), &[DNA4::A, DNA4::C, DNA4::G, DNA4::T]);
+
+        let err = "ACNT".parse::<Seq<DNA4>>().unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &SeqErrorKind::AlphabetReadError {
+                base: 'N',
+                position: Position(Some(2))
+            }
+        );
+
+        let seq: Seq<Gapped<DNA>> = "AC-GT".parse().unwrap();
+        assert_eq!(seq[2], Gapped::Gap);
+        assert_eq!(seq.to_string(), "AC-GT");
+    }
+
+    #[test]
+    fn test_slicing() {
+        let mut seq: Seq<DNA> = "AACCGGTT".parse().unwrap();
+        assert_eq!(seq[..2].to_string(), "AA");
+        assert_eq!(seq[6..].to_string(), "TT");
+        assert_eq!(seq[2..=3].len(), 2);
+        assert_eq!(seq[..].len(), 8);
+
+        seq[2..6].reverse_complement_in_place();
+        assert_eq!(seq.to_string(), "AACCGGTT");
+
+        seq[0..3].reverse_complement_in_place();
+        assert_eq!(seq.to_string(), "GTTCGGTT");
+        assert_eq!(seq.codons().len(), 2);
+    }
+
+    #[test]
+    fn test_name() {
+        let mut seq = Seq::with_name("chr1", vec![DNA::A, DNA::C]);
+        assert_eq!(seq.name(), "chr1");
+        assert_eq!(seq, Seq::from(vec![DNA::A, DNA::C]));
+
+        seq.set_name("chr2");
+        assert_eq!(seq.name(), "chr2");
+        assert_eq!(seq[..].to_seq().name(), "");
+    }
+
+    proptest! {
+        #[test]
+        fn test_display_recovers_original(s in vec(select(DNA::variants()), 0..50)) {
+            let seq = Seq::from(s);
+            assert_eq!(seq.to_string().parse::<Seq<DNA>>().unwrap(), seq);
+        }
+
+        // The in place reverse complement agrees with the iterator adapter.
+        #[test]
+        fn test_reverse_complement_in_place(s in vec(select(DNA::variants()), 0..50)) {
+            let mut seq = Seq::from(s);
+            let rc: Seq<DNA> = seq.reverse_complement().collect();
+            seq.reverse_complement_in_place();
+            assert_eq!(seq, rc);
+        }
+    }
+}
@@ -0,0 +1,517 @@
+//! MinHash and FracMinHash sketches of DNA sequences.
+//!
+//! Sketches keep a small sample of the hashed canonical [`DNA4`] kmers in a
+//! sequence, from which the Jaccard similarity, containment and Mash
+//! distance between sequences can be estimated.
+//!
+//! - Bottom-k [`MinHash`] sketches keep the `size` smallest hashes.
+//! - [`FracMinHash`] sketches keep every hash below `u64::max_value() / scaled`,
+//!   so the sketch size grows with the number of distinct kmers.
+//!
+//! Kmers are hashed with a 64-bit [`InvertibleHash`] of their canonical rank.
+//!
+//! # Examples:
+//!
+//! ```
+//! use seqrs::alphabet::DNA4;
+//! use seqrs::sketch::Sketch;
+//! use std::convert::TryFrom;
+//! use typenum::U5;
+//!
+//! let a: Vec<DNA4> = b"ACGTTGCAAGGCTAGCTAGGATCGATCGGA"
+//!     .iter()
+//!     .map(|b| DNA4::try_from(b).unwrap())
+//!     .collect();
+//!
+//! let mut sa = Sketch::minhash(5, 100);
+//! sa.add_sequence::<U5, _>(&a);
+//!
+//! let mut sb = Sketch::minhash(5, 100);
+//! sb.add_sequence::<U5, _>(a[..20].iter());
+//!
+//! assert_eq!(sa.jaccard(&sa).unwrap(), 1.0);
+//! assert_eq!(sa.mash_distance(&sa).unwrap(), 0.0);
+//! assert_eq!(sb.containment(&sa).unwrap(), 1.0);
+//! assert!(sa.jaccard(&sb).unwrap() < 1.0);
+//! ```
+//!
+//! [`DNA4`]: ../alphabet/enum.DNA4.html
+//! [`MinHash`]: enum.SketchKind.html#variant.MinHash
+//! [`FracMinHash`]: enum.SketchKind.html#variant.FracMinHash
+//! [`InvertibleHash`]: ../kmer/struct.InvertibleHash.html
+
+use std::borrow::Borrow;
+use std::collections::BTreeSet;
+use std::io;
+use std::io::{Read, Write};
+use typenum::Unsigned;
+
+use crate::alphabet::{Alphabet, DNA4};
+use crate::errors::{SeqError, SeqErrorKind};
+use crate::kmer::{IntoCanonicalKmers, InvertibleHash, KmerHasher, SimpleKmer};
+
+/// Identifies the binary sketch format.
+const MAGIC: &[u8; 4] = b"SQSK";
+const VERSION: u8 = 1;
+
+/// The rule deciding which hashes are kept in a sketch.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum SketchKind {
+    /// Keep the `size` smallest hashes.
+    MinHash { size: usize },
+    /// Keep the hashes below `u64::max_value() / scaled`, i.e. roughly one
+    /// in every `scaled` distinct kmers.
+    FracMinHash { scaled: u64 },
+}
+
+/// A MinHash or FracMinHash sketch of canonical DNA4 kmers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sketch {
+    k: usize,
+    kind: SketchKind,
+    hashes: BTreeSet<u64>,
+}
+
+impl Sketch {
+    /// Create an empty bottom-k MinHash sketch, keeping `size` hashes.
+    pub fn minhash(k: usize, size: usize) -> Self {
+        assert!(size > 0, "Sketch size must be at least 1.");
+        Self::new(k, SketchKind::MinHash { size: size })
+    }
+
+    /// Create an empty FracMinHash sketch, keeping approximately one in
+    /// `scaled` hashes.
+    pub fn frac_minhash(k: usize, scaled: u64) -> Self {
+        assert!(scaled > 0, "Sketch scale must be at least 1.");
+        Self::new(k, SketchKind::FracMinHash { scaled: scaled })
+    }
+
+    fn new(k: usize, kind: SketchKind) -> Self {
+        assert!(k > 0 && k <= 32, "Sketch kmer size must be between 1 and 32.");
+        Sketch {
+            k: k,
+            kind: kind,
+            hashes: BTreeSet::new(),
+        }
+    }
+
+    /// The kmer size of the sketch.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// The rule deciding which hashes are kept.
+    pub fn kind(&self) -> SketchKind {
+        self.kind
+    }
+
+    /// The hashes in the sketch, in ascending order.
+    pub fn hashes(&self) -> impl Iterator<Item = u64> + '_ {
+        self.hashes.iter().cloned()
+    }
+
+    /// The number of hashes in the sketch.
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    /// Returns true if the sketch contains no hashes.
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+
+    /// Add a hash to the sketch, if it is kept by the sketch rule.
+    pub fn add_hash(&mut self, hash: u64) {
+        match self.kind {
+            SketchKind::MinHash { size } => {
+                if self.hashes.len() < size {
+                    self.hashes.insert(hash);
+                } else if let Some(&max) = self.hashes.iter().next_back() {
+                    if hash < max && self.hashes.insert(hash) {
+                        self.hashes.remove(&max);
+                    }
+                }
+            }
+            SketchKind::FracMinHash { scaled } => {
+                if hash <= max_hash(scaled) {
+                    self.hashes.insert(hash);
+                }
+            }
+        }
+    }
+
+    /// Add the canonical kmers of a sequence to the sketch.
+    ///
+    /// Sequences with several records, like draft assemblies, can be
+    /// sketched by adding each record in turn.
+    /// Panics if `K` differs from the kmer size of the sketch.
+    pub fn add_sequence<K, I>(&mut self, seq: I)
+    where
+        K: Unsigned,
+        I: IntoIterator,
+        I::Item: Borrow<DNA4>,
+    {
+        assert_eq!(K::to_usize(), self.k, "Kmer size must match the sketch.");
+
+        let hasher = InvertibleHash::default();
+        for kmer in IntoCanonicalKmers::<DNA4>::canonical_kmers::<K>(seq.into_iter()) {
+            let kmer: SimpleKmer<DNA4, K> = kmer;
+            self.add_hash(hasher.hash_rank(kmer.rank()));
+        }
+    }
+
+    /// Combine two sketches of the same kind into a sketch of the union of
+    /// their sequences.
+    pub fn merge(&mut self, other: &Self) -> Result<(), SeqError> {
+        if self.k != other.k || self.kind != other.kind {
+            return Err(SeqErrorKind::IncompatibleSketches.into());
+        }
+
+        for &hash in &other.hashes {
+            self.add_hash(hash);
+        }
+        Ok(())
+    }
+
+    /// Reduce two sketches to comparable hash sets.
+    ///
+    /// MinHash sketches are compared using the bottom hashes of their union,
+    /// and FracMinHash sketches are downsampled to the larger scale.
+    fn comparable(&self, other: &Self) -> Result<(Vec<u64>, Vec<u64>), SeqError> {
+        if self.k != other.k {
+            return Err(SeqErrorKind::IncompatibleSketches.into());
+        }
+
+        match (self.kind, other.kind) {
+            (SketchKind::MinHash { .. }, SketchKind::MinHash { .. }) => {
+                // Only hashes below the largest hash of both full sketches
+                // are known to be complete in each.
+                let cutoff = match (self.is_full(), other.is_full()) {
+                    (true, true) => self.max().min(other.max()),
+                    (true, false) => self.max(),
+                    (false, true) => other.max(),
+                    (false, false) => u64::max_value(),
+                };
+
+                let filter = |s: &Self| s.hashes.iter().cloned().filter(|&h| h <= cutoff).collect();
+                Ok((filter(self), filter(other)))
+            }
+            (SketchKind::FracMinHash { scaled: a }, SketchKind::FracMinHash { scaled: b }) => {
+                let cutoff = max_hash(a.max(b));
+                let filter = |s: &Self| s.hashes.iter().cloned().filter(|&h| h <= cutoff).collect();
+                Ok((filter(self), filter(other)))
+            }
+            _ => Err(SeqErrorKind::IncompatibleSketches.into()),
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        match self.kind {
+            SketchKind::MinHash { size } => self.hashes.len() >= size,
+            SketchKind::FracMinHash { .. } => false,
+        }
+    }
+
+    fn max(&self) -> u64 {
+        self.hashes.iter().next_back().cloned().unwrap_or(0)
+    }
+
+    /// Estimate the Jaccard similarity of the kmer sets of two sequences.
+    ///
+    /// Returns an error if the sketches use different kmer sizes or kinds.
+    pub fn jaccard(&self, other: &Self) -> Result<f64, SeqError> {
+        let (a, b) = self.comparable(other)?;
+        let shared = count_shared(&a, &b);
+        let union = a.len() + b.len() - shared;
+
+        if union == 0 {
+            return Ok(0.0);
+        }
+        Ok(shared as f64 / union as f64)
+    }
+
+    /// Estimate the fraction of kmers in this sequence that are also in
+    /// `other`.
+    ///
+    /// Returns an error if the sketches use different kmer sizes or kinds.
+    pub fn containment(&self, other: &Self) -> Result<f64, SeqError> {
+        let (a, b) = self.comparable(other)?;
+
+        if a.is_empty() {
+            return Ok(0.0);
+        }
+        Ok(count_shared(&a, &b) as f64 / a.len() as f64)
+    }
+
+    /// Estimate the Mash distance between two sequences, which approximates
+    /// the per-base mutation rate between them.
+    ///
+    /// Sequences sharing no kmers have a distance of 1.
+    pub fn mash_distance(&self, other: &Self) -> Result<f64, SeqError> {
+        let j = self.jaccard(other)?;
+
+        if j <= 0.0 {
+            return Ok(1.0);
+        }
+
+        let d = -(2.0 * j / (1.0 + j)).ln() / self.k as f64;
+        Ok(d.max(0.0).min(1.0))
+    }
+
+    /// Write the sketch in a stable little-endian binary format.
+    ///
+    /// The format is a 4 byte magic string and a version byte, followed by
+    /// the kmer size as a u32, the kind as a u8 (0 for MinHash and 1 for
+    /// FracMinHash), the size or scale as a u64, the number of hashes as a
+    /// u64, and the hashes in ascending order as u64s.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let (kind, param) = match self.kind {
+            SketchKind::MinHash { size } => (0, size as u64),
+            SketchKind::FracMinHash { scaled } => (1, scaled),
+        };
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        writer.write_all(&le_bytes(self.k as u64)[..4])?;
+        writer.write_all(&[kind])?;
+        writer.write_all(&le_bytes(param))?;
+        writer.write_all(&le_bytes(self.hashes.len() as u64))?;
+
+        for &hash in &self.hashes {
+            writer.write_all(&le_bytes(hash))?;
+        }
+        Ok(())
+    }
+
+    /// Read a sketch written by [`write_to`].
+    ///
+    /// [`write_to`]: #method.write_to
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use seqrs::alphabet::DNA4::*;
+    /// use seqrs::sketch::Sketch;
+    /// use typenum::U3;
+    ///
+    /// let mut sketch = Sketch::frac_minhash(3, 2);
+    /// sketch.add_sequence::<U3, _>(&[A, C, G, T, T, G, A, C]);
+    ///
+    /// let mut buffer = Vec::new();
+    /// sketch.write_to(&mut buffer).unwrap();
+    /// assert_eq!(Sketch::read_from(&mut buffer.as_slice()).unwrap(), sketch);
+    /// ```
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut header = [0; 5];
+        reader.read_exact(&mut header)?;
+        if &header[..4] != MAGIC || header[4] != VERSION {
+            return Err(invalid_data("Not a sketch."));
+        }
+
+        let mut k = [0; 8];
+        reader.read_exact(&mut k[..4])?;
+        let k = from_le_bytes(k) as usize;
+
+        let mut kind = [0; 1];
+        reader.read_exact(&mut kind)?;
+        let param = read_u64(reader)?;
+
+        if k == 0 || k > 32 || param == 0 {
+            return Err(invalid_data("Invalid sketch parameters."));
+        }
+
+        let kind = match kind[0] {
+            0 => SketchKind::MinHash {
+                size: param as usize,
+            },
+            1 => SketchKind::FracMinHash { scaled: param },
+            _ => return Err(invalid_data("Unknown sketch kind.")),
+        };
+
+        let mut sketch = Sketch::new(k, kind);
+        let n = read_u64(reader)?;
+        for _ in 0..n {
+            sketch.add_hash(read_u64(reader)?);
+        }
+
+        if sketch.len() as u64 != n {
+            return Err(invalid_data("Sketch hashes don't match the sketch parameters."));
+        }
+        Ok(sketch)
+    }
+}
+
+/// The largest hash kept by a FracMinHash sketch.
+#[inline]
+fn max_hash(scaled: u64) -> u64 {
+    u64::max_value() / scaled
+}
+
+/// Count the values shared by two sorted slices.
+fn count_shared(a: &[u64], b: &[u64]) -> usize {
+    let mut shared = 0;
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        if a[i] < b[j] {
+            i += 1;
+        } else if a[i] > b[j] {
+            j += 1;
+        } else {
+            shared += 1;
+            i += 1;
+            j += 1;
+        }
+    }
+    shared
+}
+
+fn le_bytes(value: u64) -> [u8; 8] {
+    let mut bytes = [0; 8];
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = (value >> (8 * i)) as u8;
+    }
+    bytes
+}
+
+fn from_le_bytes(bytes: [u8; 8]) -> u64 {
+    bytes
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, &b)| acc | (u64::from(b) << (8 * i)))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(from_le_bytes(bytes))
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::DNA4::*;
+    use crate::complement::IntoReverseComplement;
+    use proptest::collection::vec;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+    use std::collections::HashSet;
+    use typenum::U7;
+
+    fn exact_hashes(seq: &[DNA4]) -> HashSet<u64> {
+        let mut sketch = Sketch::frac_minhash(7, 1);
+        sketch.add_sequence::<U7, _>(seq);
+        sketch.hashes().collect()
+    }
+
+    #[test]
+    fn test_minhash_keeps_smallest() {
+        let mut sketch = Sketch::minhash(3, 3);
+        for h in &[10, 5, 20, 1, 5, 15] {
+            sketch.add_hash(*h);
+        }
+        assert_eq!(sketch.hashes().collect::<Vec<u64>>(), vec![1, 5, 10]);
+    }
+
+    #[test]
+    fn test_incompatible() {
+        let a = Sketch::minhash(3, 10);
+        let b = Sketch::minhash(4, 10);
+        let c = Sketch::frac_minhash(3, 10);
+
+        let err = a.jaccard(&b).unwrap_err();
+        assert_eq!(err.kind(), &SeqErrorKind::IncompatibleSketches);
+        assert!(a.containment(&c).is_err());
+        assert!(a.clone().merge(&c).is_err());
+    }
+
+    #[test]
+    fn test_mash_distance_disjoint() {
+        let mut a = Sketch::minhash(3, 10);
+        a.add_sequence::<typenum::U3, _>(&[A, A, A, A]);
+        let mut b = Sketch::minhash(3, 10);
+        b.add_sequence::<typenum::U3, _>(&[A, C, G, A]);
+
+        assert_eq!(a.jaccard(&b).unwrap(), 0.0);
+        assert_eq!(a.mash_distance(&b).unwrap(), 1.0);
+    }
+
+    proptest! {
+        // With a scale of 1 a FracMinHash sketch is exact.
+        #[test]
+        fn test_exact_jaccard(
+            a in vec(select(DNA4::variants()), 0..60),
+            b in vec(select(DNA4::variants()), 0..60),
+        ) {
+            let (ha, hb) = (exact_hashes(&a), exact_hashes(&b));
+            let union = ha.union(&hb).count();
+            let shared = ha.intersection(&hb).count();
+
+            let mut sa = Sketch::frac_minhash(7, 1);
+            sa.add_sequence::<U7, _>(&a);
+            let mut sb = Sketch::frac_minhash(7, 1);
+            sb.add_sequence::<U7, _>(&b);
+
+            let expected = if union == 0 { 0.0 } else { shared as f64 / union as f64 };
+            assert_eq!(sa.jaccard(&sb).unwrap(), expected);
+            assert_eq!(sb.jaccard(&sa).unwrap(), expected);
+
+            // A big enough MinHash sketch is exact too.
+            let mut ma = Sketch::minhash(7, 1000);
+            ma.add_sequence::<U7, _>(&a);
+            let mut mb = Sketch::minhash(7, 1000);
+            mb.add_sequence::<U7, _>(&b);
+            assert_eq!(ma.jaccard(&mb).unwrap(), expected);
+        }
+
+        #[test]
+        fn test_strand_independent(seq in vec(select(DNA4::variants()), 0..100)) {
+            let rc: Vec<DNA4> = seq.iter().reverse_complement().collect();
+
+            let mut a = Sketch::minhash(7, 10);
+            a.add_sequence::<U7, _>(&seq);
+            let mut b = Sketch::minhash(7, 10);
+            b.add_sequence::<U7, _>(&rc);
+            assert_eq!(a, b);
+        }
+
+        #[test]
+        fn test_merge_equals_concatenated_records(
+            a in vec(select(DNA4::variants()), 0..60),
+            b in vec(select(DNA4::variants()), 0..60),
+        ) {
+            let mut sa = Sketch::minhash(7, 20);
+            sa.add_sequence::<U7, _>(&a);
+            let mut sb = Sketch::minhash(7, 20);
+            sb.add_sequence::<U7, _>(&b);
+            sa.merge(&sb).unwrap();
+
+            let mut both = Sketch::minhash(7, 20);
+            both.add_sequence::<U7, _>(&a);
+            both.add_sequence::<U7, _>(&b);
+            assert_eq!(sa, both);
+        }
+
+        #[test]
+        fn test_serialisation_roundtrip(
+            seq in vec(select(DNA4::variants()), 0..100),
+            frac in proptest::bool::ANY,
+        ) {
+            let mut sketch = if frac {
+                Sketch::frac_minhash(7, 3)
+            } else {
+                Sketch::minhash(7, 10)
+            };
+            sketch.add_sequence::<U7, _>(&seq);
+
+            let mut buffer = Vec::new();
+            sketch.write_to(&mut buffer).unwrap();
+            assert_eq!(buffer.len(), 26 + 8 * sketch.len());
+            assert_eq!(Sketch::read_from(&mut buffer.as_slice()).unwrap(), sketch);
+        }
+    }
+}
@@ -0,0 +1,373 @@
+//! Compact storage for assembled genomes, in the style of UCSC 2bit files.
+//!
+//! Most of a genome assembly is made of unambiguous bases, which are packed
+//! at two bits per base. Runs of `N` and other redundant bases are stored
+//! separately as a list of blocks, so long gaps between scaffolds cost
+//! almost nothing.
+//!
+//! # Examples:
+//!
+//! ```
+//! use seqrs::alphabet::DNA;
+//! use seqrs::complement::IntoReverseComplement;
+//! use seqrs::twobit::TwoBitSeq;
+//!
+//! let seq: TwoBitSeq = "ACGTNNNNNNNNNNRACGT".parse().unwrap();
+//! assert_eq!(seq.get(7), Some(DNA::N));
+//! assert_eq!(seq.n_blocks().collect::<Vec<_>>(), vec![4..14]);
+//!
+//! // The logical view is an ordinary sequence of `DNA`.
+//! let rc: String = seq.iter().reverse_complement().map(char::from).collect();
+//! assert_eq!(rc, "ACGTYNNNNNNNNNNACGT");
+//! assert_eq!(seq.reverse_complement().to_string(), rc);
+//! ```
+
+use std::convert::TryFrom;
+use std::iter::FromIterator;
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::alphabet::{DNA, DNA4};
+use crate::complement::Complement;
+use crate::errors::SeqError;
+use crate::packed::PackedSeq;
+
+/// A DNA sequence stored as packed `DNA4` bases, with blocks of redundant
+/// bases stored separately.
+///
+/// Positions inside a redundant block are stored as `A` in the packed bases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwoBitSeq {
+    bases: PackedSeq<DNA4>,
+    blocks: Vec<(Range<usize>, DNA)>,
+}
+
+impl TwoBitSeq {
+    pub fn new() -> Self {
+        TwoBitSeq {
+            bases: PackedSeq::new(),
+            blocks: Vec::new(),
+        }
+    }
+
+    /// Parse a sequence from bytes, failing at the first invalid letter.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SeqError> {
+        let mut seq = Self::new();
+        for (i, b) in bytes.iter().enumerate() {
+            seq.push(DNA::try_from(b).map_err(|e| e.at_position(i))?);
+        }
+        Ok(seq)
+    }
+
+    pub fn len(&self) -> usize {
+        self.bases.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bases.is_empty()
+    }
+
+    pub fn push(&mut self, base: DNA) {
+        let i = self.bases.len();
+        match DNA4::try_from(base) {
+            Ok(b) => self.bases.push(b),
+            Err(_) => {
+                self.bases.push(DNA4::A);
+                match self.blocks.last_mut() {
+                    Some((ref mut range, code)) if range.end == i && *code == base => {
+                        range.end += 1;
+                    }
+                    _ => self.blocks.push((i..i + 1, base)),
+                }
+            }
+        }
+    }
+
+    /// The packed unambiguous bases.
+    pub fn bases(&self) -> &PackedSeq<DNA4> {
+        &self.bases
+    }
+
+    /// The runs of redundant bases, sorted by position.
+    pub fn blocks(&self) -> &[(Range<usize>, DNA)] {
+        &self.blocks
+    }
+
+    /// The runs of `N`, sorted by position.
+    pub fn n_blocks<'a>(&'a self) -> impl Iterator<Item = Range<usize>> + 'a {
+        self.blocks
+            .iter()
+            .filter(|(_, b)| *b == DNA::N)
+            .map(|(r, _)| r.clone())
+    }
+
+    pub fn get(&self, index: usize) -> Option<DNA> {
+        let base = self.bases.get(index)?;
+        let block = self
+            .blocks
+            .binary_search_by(|(r, _)| {
+                if r.end <= index {
+                    std::cmp::Ordering::Less
+                } else if r.start > index {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .ok();
+
+        match block {
+            Some(i) => Some(self.blocks[i].1),
+            None => Some(DNA::from(base)),
+        }
+    }
+
+    /// Iterate over the logical `DNA` sequence.
+    pub fn iter(&self) -> TwoBitIter<'_> {
+        TwoBitIter {
+            seq: self,
+            start: 0,
+            end: self.len(),
+            front_block: 0,
+            back_block: self.blocks.len(),
+        }
+    }
+
+    /// The reverse complement of the sequence.
+    pub fn reverse_complement(&self) -> Self {
+        let len = self.len();
+        let mut bases = self.bases.reverse_complement();
+
+        // Keep the placeholder for redundant bases as `A`.
+        for (r, _) in self.blocks.iter() {
+            bases.fill((len - r.end)..(len - r.start), DNA4::A);
+        }
+
+        let blocks = self
+            .blocks
+            .iter()
+            .rev()
+            .map(|(r, b)| ((len - r.end)..(len - r.start), b.complement()))
+            .collect();
+
+        TwoBitSeq {
+            bases: bases,
+            blocks: blocks,
+        }
+    }
+}
+
+impl Default for TwoBitSeq {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromIterator<DNA> for TwoBitSeq {
+    fn from_iter<I: IntoIterator<Item = DNA>>(iter: I) -> Self {
+        let mut seq = Self::new();
+        seq.extend(iter);
+        seq
+    }
+}
+
+impl Extend<DNA> for TwoBitSeq {
+    fn extend<I: IntoIterator<Item = DNA>>(&mut self, iter: I) {
+        for b in iter {
+            self.push(b);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a TwoBitSeq {
+    type Item = DNA;
+    type IntoIter = TwoBitIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromStr for TwoBitSeq {
+    type Err = SeqError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl std::fmt::Display for TwoBitSeq {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for b in self.iter() {
+            write!(f, "{}", b)?;
+        }
+        Ok(())
+    }
+}
+
+/// An iterator over the logical `DNA` sequence of a [`TwoBitSeq`].
+///
+/// [`TwoBitSeq`]: struct.TwoBitSeq.html
+#[derive(Debug, Clone)]
+pub struct TwoBitIter<'a> {
+    seq: &'a TwoBitSeq,
+    start: usize,
+    end: usize,
+    // Blocks before `front_block` end before `start`, and blocks from
+    // `back_block` onwards start at or after `end`.
+    front_block: usize,
+    back_block: usize,
+}
+
+impl<'a> Iterator for TwoBitIter<'a> {
+    type Item = DNA;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start >= self.end {
+            return None;
+        }
+
+        let i = self.start;
+        self.start += 1;
+
+        let blocks = &self.seq.blocks;
+        while self.front_block < blocks.len() && blocks[self.front_block].0.end <= i {
+            self.front_block += 1;
+        }
+
+        match blocks.get(self.front_block) {
+            Some((r, b)) if r.start <= i => Some(*b),
+            _ => self.seq.bases.get(i).map(DNA::from),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for TwoBitIter<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start >= self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        let i = self.end;
+
+        let blocks = &self.seq.blocks;
+        while self.back_block > 0 && blocks[self.back_block - 1].0.start > i {
+            self.back_block -= 1;
+        }
+
+        match self.back_block.checked_sub(1).map(|j| &blocks[j]) {
+            Some((r, b)) if r.end > i => Some(*b),
+            _ => self.seq.bases.get(i).map(DNA::from),
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for TwoBitIter<'a> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::{Alphabet, AA};
+    use crate::codon::IntoCodons;
+    use crate::complement::IntoReverseComplement;
+    use crate::stopped::Stopped;
+    use crate::translate::{IntoTranslate, NCBITransTable};
+    use proptest::collection::vec;
+    use proptest::sample::select;
+    use proptest::{proptest, proptest_helper};
+
+    #[test]
+    fn test_blocks() {
+        let seq: TwoBitSeq = "NNACRRRNNNTN".parse().unwrap();
+        assert_eq!(
+            seq.blocks(),
+            &[
+                (0..2, DNA::N),
+                (4..7, DNA::R),
+                (7..10, DNA::N),
+                (11..12, DNA::N)
+            ]
+        );
+        assert_eq!(seq.bases().get(0), Some(DNA4::A));
+        assert_eq!(seq.get(10), Some(DNA::T));
+        assert_eq!(seq.get(12), None);
+    }
+
+    #[test]
+    fn test_translate() {
+        let seq: TwoBitSeq = "ATGNNNTAA".parse().unwrap();
+        let protein: Vec<Stopped<AA>> = seq
+            .iter()
+            .codons()
+            .translate(NCBITransTable::Standard)
+            .collect();
+        assert_eq!(
+            protein,
+            vec![Stopped::Res(AA::M), Stopped::StopOr(AA::X), Stopped::Stop]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_iter_recovers_original(
+            bases in vec(select(vec![DNA::A, DNA::C, DNA::G, DNA::T, DNA::N, DNA::R]), 0..100)
+        ) {
+            let seq: TwoBitSeq = bases.iter().cloned().collect();
+            assert_eq!(seq.len(), bases.len());
+            assert_eq!(seq.iter().collect::<Vec<DNA>>(), bases.clone());
+
+            let rev: Vec<DNA> = seq.iter().rev().collect();
+            let expected: Vec<DNA> = bases.iter().rev().cloned().collect();
+            assert_eq!(rev, expected);
+
+            for (i, b) in bases.iter().enumerate() {
+                assert_eq!(seq.get(i), Some(*b));
+            }
+        }
+
+        // Iterating from both ends meets in the middle.
+        #[test]
+        fn test_iter_both_ends(bases in vec(select(DNA::variants()), 0..100)) {
+            let seq: TwoBitSeq = bases.iter().cloned().collect();
+            let mut iter = seq.iter();
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+
+            while let Some(b) = iter.next() {
+                front.push(b);
+                if let Some(b) = iter.next_back() {
+                    back.push(b);
+                }
+            }
+
+            front.extend(back.into_iter().rev());
+            assert_eq!(front, bases);
+        }
+
+        #[test]
+        fn test_reverse_complement(bases in vec(select(DNA::variants()), 0..100)) {
+            let seq: TwoBitSeq = bases.iter().cloned().collect();
+            let expected: TwoBitSeq = bases.iter().reverse_complement().collect();
+            assert_eq!(seq.reverse_complement(), expected);
+        }
+    }
+}
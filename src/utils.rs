@@ -1,6 +1,6 @@
 /// Small utility functions.
 /// Yes yes I know that utils is a bad name. I couldn't think of anything.
-use crate::errors::{SeqError, SeqErrorKind};
+use crate::errors::{Position, SeqError, SeqErrorKind};
 
 /// Safely casts char as byte, raising AlphabetReadError if overflow.
 pub(crate) fn char_to_byte(c: &char) -> Result<u8, SeqError> {
@@ -9,6 +9,10 @@ pub(crate) fn char_to_byte(c: &char) -> Result<u8, SeqError> {
     if int <= (u8::max_value() as u32) {
         Ok(int as u8)
     } else {
-        Err(SeqErrorKind::AlphabetReadError { base: *c }.into())
+        Err(SeqErrorKind::AlphabetReadError {
+            base: *c,
+            position: Position(None),
+        }
+        .into())
     }
 }